@@ -0,0 +1,38 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use sep5::SeedPhrase;
+
+const TWELVE: &str = "illness spike retreat truth genius clock brain pass fit cave bargain toe";
+
+fn derive_accounts(c: &mut Criterion) {
+    let phrase: SeedPhrase = TWELVE.parse().unwrap();
+    let mut group = c.benchmark_group("derive_accounts");
+    for count in [1, 10, 100] {
+        group.bench_with_input(
+            BenchmarkId::new("SeedPhrase::from_path_index", count),
+            &count,
+            |b, &count| {
+                b.iter(|| {
+                    for i in 0..count {
+                        black_box(phrase.from_path_index(i, None).unwrap());
+                    }
+                });
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("DerivedRoot::from_path_index", count),
+            &count,
+            |b, &count| {
+                b.iter(|| {
+                    let root = phrase.derived_root(None).unwrap();
+                    for i in 0..count {
+                        black_box(root.from_path_index(i).unwrap());
+                    }
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, derive_accounts);
+criterion_main!(benches);
@@ -0,0 +1,292 @@
+//! Command line tool to generate seed phrases and derive SEP-5 keys, built
+//! with the `cli` feature
+
+use std::{
+    fs,
+    io::{self, Read},
+    ops::Range,
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use clap::{Args, Parser, Subcommand};
+use sep5::{language, validation, DerivationPath, Language, MnemonicType, SeedPhrase};
+use serde_json::{json, Value};
+use stellar_strkey::ed25519::PublicKey;
+use zeroize::Zeroizing;
+
+#[derive(Parser)]
+#[command(
+    name = "sep5",
+    version,
+    about = "Generate and derive SEP-5 Stellar keys"
+)]
+struct Cli {
+    /// Print JSON instead of text
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generate a random seed phrase
+    Generate {
+        /// Number of words: 12, 15, 18, 21 or 24
+        #[arg(long, short, default_value = "24", value_parser = parse_word_count)]
+        words: MnemonicType,
+
+        /// Wordlist, for example english, japanese or chinese-simplified
+        #[arg(long, short, default_value = "english", value_parser = parse_language)]
+        language: Language,
+    },
+    /// Derive the keys at m/44'/148'/{n}'
+    Derive {
+        #[command(flatten)]
+        input: Input,
+
+        /// Account index
+        #[arg(long, short, conflicts_with = "range")]
+        index: Option<usize>,
+
+        /// Account indexes, end excluded, for example 0..10
+        #[arg(long, short, value_parser = parse_range)]
+        range: Option<Range<usize>>,
+
+        /// Also print the secret keys
+        #[arg(long)]
+        show_secret: bool,
+    },
+    /// Show the key at m/44'/148'
+    Inspect {
+        #[command(flatten)]
+        input: Input,
+
+        /// Also print the seed and the secret key
+        #[arg(long)]
+        show_secret: bool,
+    },
+    /// Check a seed phrase, and optionally that it derives a public key
+    Verify {
+        #[command(flatten)]
+        input: Input,
+
+        /// Public key expected at m/44'/148'/{n}'
+        #[arg(long)]
+        public_key: Option<PublicKey>,
+
+        /// Highest account index searched for the public key
+        #[arg(long, default_value = "100")]
+        max_index: usize,
+    },
+}
+
+/// Where the seed phrase and passphrase come from
+#[derive(Args)]
+struct Input {
+    /// Read the seed phrase from a file instead of stdin
+    #[arg(long, short)]
+    file: Option<PathBuf>,
+
+    /// Prompt for a BIP-39 passphrase
+    #[arg(long, short)]
+    passphrase: bool,
+}
+
+type CliResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+/// Result of a command, printed as text or JSON
+struct Output {
+    json: Value,
+    text: String,
+    success: bool,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli.command) {
+        Ok(output) => {
+            if cli.json {
+                println!("{}", output.json);
+            } else {
+                println!("{}", output.text);
+            }
+            if output.success {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run(command: Command) -> CliResult<Output> {
+    match command {
+        Command::Generate { words, language } => {
+            let seed_phrase = SeedPhrase::random_in(words, language)?;
+            Ok(Output {
+                json: json!({
+                    "phrase": seed_phrase.display_phrase(),
+                    "language": language::name(language),
+                }),
+                text: seed_phrase.display_phrase(),
+                success: true,
+            })
+        }
+        Command::Derive {
+            input,
+            index,
+            range,
+            show_secret,
+        } => {
+            let (seed_phrase, passphrase) = input.read()?;
+            let passphrase = passphrase.as_ref().map(|p| p.as_str());
+            let range = match range {
+                Some(range) => range,
+                None => {
+                    let index = index.unwrap_or_default();
+                    index..index.checked_add(1).ok_or("account index is too large")?
+                }
+            };
+            let keys = seed_phrase.derive_range(range.clone(), passphrase)?;
+            let mut json = Vec::new();
+            let mut text = Vec::new();
+            for (index, key) in range.zip(&keys) {
+                let path = DerivationPath::stellar_account(index as u32)?.to_string();
+                let mut line = format!("{index} {path} {}", key.public());
+                let mut account = json!({
+                    "index": index,
+                    "path": path,
+                    "public_key": key.public().to_string().as_str(),
+                });
+                if show_secret {
+                    line.push_str(&format!(" {}", key.private()));
+                    account["secret_key"] = json!(key.private().to_string().as_str());
+                }
+                text.push(line);
+                json.push(account);
+            }
+            Ok(Output {
+                json: json.into(),
+                text: text.join("\n"),
+                success: true,
+            })
+        }
+        Command::Inspect { input, show_secret } => {
+            let (seed_phrase, passphrase) = input.read()?;
+            let passphrase = passphrase.as_ref().map(|p| p.as_str());
+            let key = seed_phrase.empty_key(passphrase)?;
+            let language = language::name(seed_phrase.language());
+            let mut text = format!(
+                "language: {language}\npath: m/44'/148'\npublic key: {}",
+                key.public()
+            );
+            let mut json = json!({
+                "language": language,
+                "path": "m/44'/148'",
+                "public_key": key.public().to_string().as_str(),
+            });
+            if show_secret {
+                let seed = format!("{:x}", seed_phrase.to_seed(passphrase));
+                text.push_str(&format!("\nseed: {seed}\nsecret key: {}", key.private()));
+                json["seed"] = json!(seed);
+                json["secret_key"] = json!(key.private().to_string().as_str());
+            }
+            Ok(Output {
+                json,
+                text,
+                success: true,
+            })
+        }
+        Command::Verify {
+            input,
+            public_key,
+            max_index,
+        } => {
+            let phrase = read_phrase(input.file.as_ref())?;
+            let report = validation::validate(&phrase);
+            if !report.is_valid() {
+                return Ok(Output {
+                    json: json!({ "valid": false, "problems": report.to_string() }),
+                    text: format!("invalid: {report}"),
+                    success: false,
+                });
+            }
+            let Some(public_key) = public_key else {
+                return Ok(Output {
+                    json: json!({ "valid": true }),
+                    text: "valid".to_string(),
+                    success: true,
+                });
+            };
+            let seed_phrase = SeedPhrase::from_seed_phrase(&phrase)?;
+            let passphrase = input.passphrase()?;
+            let passphrase = passphrase.as_ref().map(|p| p.as_str());
+            let account = seed_phrase.find_account(&public_key, passphrase, max_index)?;
+            Ok(match account {
+                Some((index, path)) => Output {
+                    json: json!({ "valid": true, "index": index, "path": path.to_string() }),
+                    text: format!("valid, {public_key} is account {index} at {path}"),
+                    success: true,
+                },
+                None => Output {
+                    json: json!({ "valid": true, "index": null }),
+                    text: format!("valid, but {public_key} is not among accounts 0 to {max_index}"),
+                    success: false,
+                },
+            })
+        }
+    }
+}
+
+impl Input {
+    fn read(&self) -> CliResult<(SeedPhrase, Option<Zeroizing<String>>)> {
+        let seed_phrase = SeedPhrase::from_seed_phrase(&read_phrase(self.file.as_ref())?)?;
+        Ok((seed_phrase, self.passphrase()?))
+    }
+
+    fn passphrase(&self) -> io::Result<Option<Zeroizing<String>>> {
+        if !self.passphrase {
+            return Ok(None);
+        }
+        rpassword::prompt_password("Passphrase: ").map(|p| Some(Zeroizing::new(p)))
+    }
+}
+
+fn read_phrase(file: Option<&PathBuf>) -> io::Result<Zeroizing<String>> {
+    let mut phrase = Zeroizing::new(String::new());
+    match file {
+        Some(file) => *phrase = fs::read_to_string(file)?,
+        None => {
+            io::stdin().read_to_string(&mut phrase)?;
+        }
+    }
+    Ok(phrase)
+}
+
+fn parse_language(s: &str) -> Result<Language, String> {
+    language::from_name(s).ok_or_else(|| {
+        let names = language::LANGUAGES.map(language::name);
+        format!("expected one of {}", names.join(", "))
+    })
+}
+
+fn parse_word_count(s: &str) -> Result<MnemonicType, String> {
+    s.parse()
+        .ok()
+        .and_then(|count| MnemonicType::for_word_count(count).ok())
+        .ok_or_else(|| "expected 12, 15, 18, 21 or 24".to_string())
+}
+
+fn parse_range(s: &str) -> Result<Range<usize>, String> {
+    let (start, end) = s.split_once("..").ok_or("expected START..END")?;
+    let start = start.parse().map_err(|_| "invalid range start")?;
+    let end = end.parse().map_err(|_| "invalid range end")?;
+    Ok(start..end)
+}
@@ -0,0 +1,112 @@
+//! BIP-85 deterministic entropy, to derive child secrets from one seed.
+//!
+//! Children are derived with secp256k1 BIP-32 at `m/83696968'/{app}'/...`,
+//! independently of the SEP-5 keys, so a child can be handed out without
+//! revealing the parent seed.
+
+use std::ops::RangeInclusive;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use hmac::{digest::FixedOutput, Hmac, Mac};
+use ows_signer::{Curve, HdDeriver};
+use sha2::Sha512;
+use zeroize::Zeroizing;
+
+use crate::{
+    derivation_path::DerivationPath, error::Error, language::Language, seed_phrase::SeedPhrase,
+};
+
+const PURPOSE: u32 = 83696968;
+const BIP39_APPLICATION: u32 = 39;
+const HEX_APPLICATION: u32 = 128169;
+const PASSWORD_APPLICATION: u32 = 707764;
+const HEX_LENGTHS: RangeInclusive<usize> = 16..=64;
+const PASSWORD_LENGTHS: RangeInclusive<usize> = 20..=86;
+
+/// The 64 bytes of entropy at `path`, which must start with `m/83696968'`
+pub fn entropy(seed: &[u8], path: &DerivationPath) -> Result<Zeroizing<[u8; 64]>, Error> {
+    let path = path.to_string();
+    let key = HdDeriver::derive(seed, &path, Curve::Secp256k1)
+        .map_err(|source| Error::Derivation { path, source })?;
+    let mut mac = Hmac::<Sha512>::new_from_slice(b"bip-entropy-from-k")
+        .expect("HMAC can take key of any size");
+    mac.update(key.expose());
+    Ok(Zeroizing::new(mac.finalize_fixed().into()))
+}
+
+/// Child BIP-39 phrase of `word_count` words at
+/// `m/83696968'/39'/{language}'/{words}'/{index}'`, BIP-85 defining only
+/// 12, 18 and 24 words
+pub fn mnemonic(
+    seed: &[u8],
+    language: Language,
+    word_count: bip39::MnemonicType,
+    index: u32,
+) -> Result<SeedPhrase, Error> {
+    if !matches!(
+        word_count,
+        bip39::MnemonicType::Words12 | bip39::MnemonicType::Words18 | bip39::MnemonicType::Words24
+    ) {
+        return Err(Error::UnsupportedWordCount(word_count.word_count()));
+    }
+    let path = DerivationPath::new(&[
+        PURPOSE,
+        BIP39_APPLICATION,
+        language_code(language),
+        word_count.word_count() as u32,
+        index,
+    ])?;
+    let entropy = entropy(seed, &path)?;
+    SeedPhrase::from_entropy_in(&entropy[..word_count.entropy_bits() / 8], language)
+}
+
+/// `length` bytes of hex encoded entropy at `m/83696968'/128169'/{length}'/{index}'`,
+/// `length` being between 16 and 64
+pub fn hex(seed: &[u8], length: usize, index: u32) -> Result<Zeroizing<String>, Error> {
+    check_length(length, HEX_LENGTHS)?;
+    let path = DerivationPath::new(&[PURPOSE, HEX_APPLICATION, length as u32, index])?;
+    let entropy = entropy(seed, &path)?;
+    let mut hex = Zeroizing::new(String::with_capacity(length * 2));
+    for byte in &entropy[..length] {
+        hex.push(char::from_digit((byte >> 4).into(), 16).unwrap_or_default());
+        hex.push(char::from_digit((byte & 0xf).into(), 16).unwrap_or_default());
+    }
+    Ok(hex)
+}
+
+/// Base64 password of `length` characters at
+/// `m/83696968'/707764'/{length}'/{index}'`, `length` being between 20 and 86
+pub fn password(seed: &[u8], length: usize, index: u32) -> Result<Zeroizing<String>, Error> {
+    check_length(length, PASSWORD_LENGTHS)?;
+    let path = DerivationPath::new(&[PURPOSE, PASSWORD_APPLICATION, length as u32, index])?;
+    let entropy = entropy(seed, &path)?;
+    let mut password = Zeroizing::new(STANDARD.encode(*entropy));
+    password.truncate(length);
+    Ok(password)
+}
+
+/// Language index used in BIP-85 BIP-39 paths
+fn language_code(language: Language) -> u32 {
+    match language {
+        Language::English => 0,
+        Language::Japanese => 1,
+        Language::Korean => 2,
+        Language::Spanish => 3,
+        Language::ChineseSimplified => 4,
+        Language::ChineseTraditional => 5,
+        Language::French => 6,
+        Language::Italian => 7,
+    }
+}
+
+fn check_length(length: usize, lengths: RangeInclusive<usize>) -> Result<(), Error> {
+    if lengths.contains(&length) {
+        Ok(())
+    } else {
+        Err(Error::InvalidLength {
+            length,
+            min: *lengths.start(),
+            max: *lengths.end(),
+        })
+    }
+}
@@ -0,0 +1,137 @@
+//! Derivation presets of ed25519 chains using SLIP-10, to derive accounts of
+//! several chains from one seed phrase.
+//!
+//! Chains deriving ed25519 keys with BIP32-Ed25519 instead of SLIP-10, such
+//! as Algorand and Cardano, cannot be derived by this crate.
+
+use blake2::{digest::consts::U32, Blake2b};
+use sha2::Digest;
+use sha3::Sha3_256;
+use stellar_strkey::ed25519::PublicKey;
+
+use crate::{
+    derivation_path::{self, DerivationPath},
+    error::Error,
+    key_pair::KeyPair,
+};
+
+/// Text encoding of a chain's account addresses
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AddressFormat {
+    /// Strkey public key (G...)
+    Stellar,
+    /// Base58 public key
+    Base58,
+    /// `0x` and the hex SHA3-256 of the public key and the ed25519 scheme byte
+    Aptos,
+    /// `0x` and the hex BLAKE2b-256 of the ed25519 flag byte and the public key
+    Sui,
+    /// Hex public key, the implicit account ID
+    Hex,
+}
+
+/// BIP-44 layout and address format of a chain
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoinProfile {
+    pub name: &'static str,
+    /// SLIP-44 coin type
+    pub coin_type: u32,
+    /// Hardened indexes following the account index
+    pub suffix: &'static [u32],
+    pub address_format: AddressFormat,
+}
+
+impl CoinProfile {
+    /// `m/44'/148'/{n}'`, as in SEP-5
+    pub const STELLAR: Self = Self {
+        name: "stellar",
+        coin_type: 148,
+        suffix: &[],
+        address_format: AddressFormat::Stellar,
+    };
+    /// `m/44'/501'/{n}'/0'`, as in Phantom and Solflare
+    pub const SOLANA: Self = Self {
+        name: "solana",
+        coin_type: 501,
+        suffix: &[0],
+        address_format: AddressFormat::Base58,
+    };
+    /// `m/44'/637'/{n}'/0'/0'`
+    pub const APTOS: Self = Self {
+        name: "aptos",
+        coin_type: 637,
+        suffix: &[0, 0],
+        address_format: AddressFormat::Aptos,
+    };
+    /// `m/44'/784'/{n}'/0'/0'`
+    pub const SUI: Self = Self {
+        name: "sui",
+        coin_type: 784,
+        suffix: &[0, 0],
+        address_format: AddressFormat::Sui,
+    };
+    /// `m/44'/397'/{n}'`, with implicit account addresses
+    pub const NEAR: Self = Self {
+        name: "near",
+        coin_type: 397,
+        suffix: &[],
+        address_format: AddressFormat::Hex,
+    };
+
+    /// Every preset
+    pub const ALL: &'static [Self] = &[
+        Self::STELLAR,
+        Self::SOLANA,
+        Self::APTOS,
+        Self::SUI,
+        Self::NEAR,
+    ];
+
+    /// Preset named `name`, e.g. `"solana"`
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find(|coin| coin.name.eq_ignore_ascii_case(name))
+            .copied()
+    }
+
+    /// The `m/44'/{coin_type}'` node
+    pub fn root(&self) -> DerivationPath {
+        DerivationPath::new(&[44, self.coin_type]).expect("coin type is below 2^31")
+    }
+
+    /// Path of the account `n`
+    pub fn account_path(&self, n: usize) -> Result<DerivationPath, Error> {
+        derivation_path::account_path(&[44, self.coin_type], n, self.suffix)
+    }
+
+    /// Address of the account with `public_key`
+    pub fn address(&self, public_key: &PublicKey) -> String {
+        match self.address_format {
+            AddressFormat::Stellar => public_key.to_string().as_str().to_string(),
+            AddressFormat::Base58 => bs58::encode(public_key.0).into_string(),
+            AddressFormat::Aptos => {
+                let hash = Sha3_256::new()
+                    .chain_update(public_key.0)
+                    .chain_update([0])
+                    .finalize();
+                format!("0x{}", hex::encode(hash))
+            }
+            AddressFormat::Sui => {
+                let hash = Blake2b::<U32>::new()
+                    .chain_update([0])
+                    .chain_update(public_key.0)
+                    .finalize();
+                format!("0x{}", hex::encode(hash))
+            }
+            AddressFormat::Hex => hex::encode(public_key.0),
+        }
+    }
+}
+
+impl KeyPair {
+    /// Address of this key on the chain of `coin`
+    pub fn address(&self, coin: &CoinProfile) -> String {
+        coin.address(&self.public())
+    }
+}
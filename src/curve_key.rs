@@ -0,0 +1,105 @@
+//! Keys of the curves a [`SeedPhrase`](crate::SeedPhrase) can derive, to get
+//! Stellar and e.g. EVM keys from one backup
+
+use k256::{elliptic_curve::sec1::ToEncodedPoint, SecretKey};
+use ows_signer::Curve;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+use crate::{error::Error, key_pair::KeyPair};
+
+/// A derived key of either curve
+#[derive(Debug)]
+pub enum CurveKey {
+    Ed25519(KeyPair),
+    Secp256k1(Secp256k1KeyPair),
+}
+
+impl CurveKey {
+    pub fn curve(&self) -> Curve {
+        match self {
+            Self::Ed25519(_) => Curve::Ed25519,
+            Self::Secp256k1(_) => Curve::Secp256k1,
+        }
+    }
+
+    /// Public key bytes: 32 for ed25519, 33 (compressed SEC1) for secp256k1
+    pub fn public_key(&self) -> Vec<u8> {
+        match self {
+            Self::Ed25519(key) => key.public().0.to_vec(),
+            Self::Secp256k1(key) => key.public_key().to_vec(),
+        }
+    }
+
+    /// Raw 32-byte private key
+    pub fn private_key(&self) -> Zeroizing<[u8; 32]> {
+        match self {
+            Self::Ed25519(key) => Zeroizing::new(key.private_key),
+            Self::Secp256k1(key) => Zeroizing::new(key.private_key),
+        }
+    }
+
+    pub fn as_ed25519(&self) -> Option<&KeyPair> {
+        match self {
+            Self::Ed25519(key) => Some(key),
+            Self::Secp256k1(_) => None,
+        }
+    }
+
+    pub fn as_secp256k1(&self) -> Option<&Secp256k1KeyPair> {
+        match self {
+            Self::Ed25519(_) => None,
+            Self::Secp256k1(key) => Some(key),
+        }
+    }
+}
+
+/// A secp256k1 key pair whose private key is zeroized on drop
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct Secp256k1KeyPair {
+    private_key: [u8; 32],
+}
+
+impl Secp256k1KeyPair {
+    /// Key pair of a raw private key, which must be a valid non-zero scalar
+    pub fn from_bytes(private_key: [u8; 32]) -> Result<Self, Error> {
+        SecretKey::from_slice(&private_key).map_err(|_| Error::InvalidSecretKey)?;
+        Ok(Self { private_key })
+    }
+
+    /// Copy of the private key; unlike `Secp256k1KeyPair` it is not wiped on
+    /// drop
+    pub fn private(&self) -> [u8; 32] {
+        self.private_key
+    }
+
+    /// Compressed SEC1 public key
+    pub fn public_key(&self) -> [u8; 33] {
+        let point = self.secret_key().public_key().to_encoded_point(true);
+        point
+            .as_bytes()
+            .try_into()
+            .expect("compressed point has 33 bytes")
+    }
+
+    /// Uncompressed SEC1 public key, starting with `0x04`
+    pub fn uncompressed_public_key(&self) -> [u8; 65] {
+        let point = self.secret_key().public_key().to_encoded_point(false);
+        point
+            .as_bytes()
+            .try_into()
+            .expect("uncompressed point has 65 bytes")
+    }
+
+    fn secret_key(&self) -> SecretKey {
+        SecretKey::from_slice(&self.private_key).expect("private key is checked on creation")
+    }
+}
+
+impl std::fmt::Debug for Secp256k1KeyPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Secp256k1KeyPair")
+            .field("public_key", &hex::encode(self.public_key()))
+            .field("private_key", &"[REDACTED]")
+            .finish()
+    }
+}
@@ -0,0 +1,235 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::error::{Error, PathError, SegmentError};
+
+/// A hardened-only BIP-32 derivation path such as `m/44'/148'/0'`.
+///
+/// SLIP-10 cannot derive non-hardened ed25519 children, so every segment is
+/// hardened. Indexes are stored without the hardened bit.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DerivationPath(Vec<u32>);
+
+impl DerivationPath {
+    /// Largest index that can be hardened
+    pub const MAX_INDEX: u32 = 0x7fff_ffff;
+
+    /// Build a path from hardened indexes, e.g. `[44, 148, 0]` for `m/44'/148'/0'`
+    pub fn new(indexes: &[u32]) -> Result<Self, PathError> {
+        let path = Self(indexes.to_vec());
+        match indexes.iter().position(|i| *i > Self::MAX_INDEX) {
+            Some(position) => Err(PathError::InvalidSegment {
+                path: path.to_string(),
+                position: position + 1,
+                segment: indexes[position].to_string(),
+                reason: SegmentError::OutOfRange,
+            }),
+            None => Ok(path),
+        }
+    }
+
+    /// The SEP-5 root `m/44'/148'`
+    pub fn stellar() -> Self {
+        Self(vec![44, 148])
+    }
+
+    /// The SEP-5 account path `m/44'/148'/{index}'`
+    pub fn stellar_account(index: u32) -> Result<Self, PathError> {
+        Self::stellar().child(index)
+    }
+
+    /// This path extended with the hardened child `index`
+    pub fn child(&self, index: u32) -> Result<Self, PathError> {
+        let mut indexes = self.0.clone();
+        indexes.push(index);
+        Self::new(&indexes)
+    }
+
+    /// Hardened indexes of each segment after `m`, without the hardened bit
+    pub fn indexes(&self) -> &[u32] {
+        &self.0
+    }
+
+    /// Number of segments after `m`
+    pub fn depth(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl Display for DerivationPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("m")?;
+        for index in &self.0 {
+            write!(f, "/{index}'")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = PathError;
+
+    /// Parses `m/44'/148'/0'`. Hardened segments may use `'`, `h` or `H`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let indexes = parse(s, true)?
+            .into_iter()
+            .map(|(index, _)| index)
+            .collect();
+        Ok(Self(indexes))
+    }
+}
+
+impl TryFrom<&Bip32Path> for DerivationPath {
+    type Error = PathError;
+
+    /// Fails with [`SegmentError::NotHardened`] if any segment is not hardened
+    fn try_from(path: &Bip32Path) -> Result<Self, Self::Error> {
+        match path.0.iter().position(|i| *i < Bip32Path::HARDENED) {
+            Some(position) => Err(PathError::InvalidSegment {
+                path: path.to_string(),
+                position: position + 1,
+                segment: path.0[position].to_string(),
+                reason: SegmentError::NotHardened,
+            }),
+            None => Ok(Self(
+                path.0.iter().map(|i| i & !Bip32Path::HARDENED).collect(),
+            )),
+        }
+    }
+}
+
+/// A BIP-32 derivation path which may mix hardened and non-hardened
+/// segments, such as `m/44'/60'/0'/0/0` for secp256k1 keys.
+///
+/// Indexes are stored with the hardened bit.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Bip32Path(Vec<u32>);
+
+impl Bip32Path {
+    /// Bit set on the index of hardened segments
+    pub const HARDENED: u32 = 0x8000_0000;
+
+    /// Build a path from indexes with the hardened bit
+    pub fn new(indexes: &[u32]) -> Self {
+        Self(indexes.to_vec())
+    }
+
+    pub fn indexes(&self) -> &[u32] {
+        &self.0
+    }
+
+    /// Number of segments after `m`
+    pub fn depth(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether every segment is hardened, as ed25519 requires
+    pub fn is_hardened(&self) -> bool {
+        self.0.iter().all(|i| *i >= Self::HARDENED)
+    }
+}
+
+impl From<DerivationPath> for Bip32Path {
+    fn from(path: DerivationPath) -> Self {
+        Self(path.0.iter().map(|i| i | Self::HARDENED).collect())
+    }
+}
+
+impl Display for Bip32Path {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("m")?;
+        for index in &self.0 {
+            match index.checked_sub(Self::HARDENED) {
+                Some(index) => write!(f, "/{index}'")?,
+                None => write!(f, "/{index}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Bip32Path {
+    type Err = PathError;
+
+    /// Parses `m/44'/60'/0'/0/0`. Hardened segments may use `'`, `h` or `H`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let indexes = parse(s, false)?
+            .into_iter()
+            .map(|(index, hardened)| {
+                if hardened {
+                    index | Self::HARDENED
+                } else {
+                    index
+                }
+            })
+            .collect();
+        Ok(Self(indexes))
+    }
+}
+
+/// Indexes of the segments of `s`, without the hardened bit, and whether they
+/// are hardened
+fn parse(s: &str, hardened_only: bool) -> Result<Vec<(u32, bool)>, PathError> {
+    let mut segments = s.split('/');
+    if segments.next() != Some("m") {
+        return Err(PathError::MissingRoot(s.to_string()));
+    }
+    segments
+        .enumerate()
+        .map(|(i, segment)| {
+            parse_segment(segment, hardened_only).map_err(|reason| PathError::InvalidSegment {
+                path: s.to_string(),
+                position: i + 1,
+                segment: segment.to_string(),
+                reason,
+            })
+        })
+        .collect()
+}
+
+fn parse_segment(segment: &str, hardened_only: bool) -> Result<(u32, bool), SegmentError> {
+    if segment.is_empty() {
+        return Err(SegmentError::Empty);
+    }
+    let (index, hardened) = match segment.strip_suffix(['\'', 'h', 'H']) {
+        Some(index) => (index, true),
+        None => (segment, false),
+    };
+    if index.is_empty() || !index.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(SegmentError::NotANumber);
+    }
+    let index = index
+        .parse::<u32>()
+        .ok()
+        .filter(|i| *i <= DerivationPath::MAX_INDEX)
+        .ok_or(SegmentError::OutOfRange)?;
+    if hardened_only && !hardened {
+        return Err(SegmentError::NotHardened);
+    }
+    Ok((index, hardened))
+}
+
+/// Path of the account `n` between the hardened indexes `prefix` and
+/// `suffix`, as laid out by path schemes and coin profiles
+pub(crate) fn account_path(
+    prefix: &[u32],
+    n: usize,
+    suffix: &[u32],
+) -> Result<DerivationPath, Error> {
+    let invalid_index = || Error::InvalidIndex {
+        path: display_path(prefix, n, suffix),
+    };
+    let mut indexes = prefix.to_vec();
+    indexes.push(u32::try_from(n).map_err(|_| invalid_index())?);
+    indexes.extend_from_slice(suffix);
+    DerivationPath::new(&indexes).map_err(|_| invalid_index())
+}
+
+/// Path of an account `n` which may be out of range, for error messages
+pub(crate) fn display_path(prefix: &[u32], n: usize, suffix: &[u32]) -> String {
+    let segments = prefix
+        .iter()
+        .map(u32::to_string)
+        .chain([n.to_string()])
+        .chain(suffix.iter().map(u32::to_string));
+    segments.fold("m".to_string(), |path, segment| path + "/" + &segment + "'")
+}
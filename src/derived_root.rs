@@ -0,0 +1,112 @@
+use std::ops::Range;
+
+use stellar_strkey::ed25519::PublicKey;
+
+use crate::{
+    derivation_path::DerivationPath, error::Error, extended_key::ExtendedKey, key_pair::KeyPair,
+    slip10::Node,
+};
+
+/// The SEP-5 `m/44'/148'` node of a seed phrase.
+///
+/// Computing the BIP-39 seed runs 2048 rounds of PBKDF2, so deriving many
+/// accounts through [`SeedPhrase`](crate::SeedPhrase) is slow. A
+/// `DerivedRoot` keeps the extended key (private key and chain code) at
+/// `m/44'/148'` and derives accounts from there. It is zeroized on drop.
+#[derive(Clone)]
+pub struct DerivedRoot {
+    node: Node,
+}
+
+impl DerivedRoot {
+    pub(crate) fn from_seed(seed: &[u8]) -> Self {
+        let node = DerivationPath::stellar()
+            .indexes()
+            .iter()
+            .fold(Node::master(seed), |node, index| node.derive(*index));
+        Self { node }
+    }
+
+    /// Generate a key from a path string, anything after `m/44'/148'`
+    pub fn from_path_string(&self, path: &str) -> Result<KeyPair, Error> {
+        let path: DerivationPath = format!("m{path}").parse()?;
+        let node = path
+            .indexes()
+            .iter()
+            .fold(self.node.clone(), |node, index| node.derive(*index));
+        Ok(KeyPair {
+            private_key: *node.key(),
+        })
+    }
+
+    /// Generate a key from a path index, anything after `m/44'/148'/{num}'`
+    pub fn from_path_index(&self, num: usize) -> Result<KeyPair, Error> {
+        let index = u32::try_from(num)
+            .ok()
+            .filter(|i| *i <= DerivationPath::MAX_INDEX)
+            .ok_or_else(|| Error::InvalidIndex {
+                path: format!("m/44'/148'/{num}'"),
+            })?;
+        Ok(KeyPair {
+            private_key: *self.node.derive(index).key(),
+        })
+    }
+
+    /// Lazily derive the accounts `m/44'/148'/{n}'` for every `n` in `range`
+    pub fn accounts(
+        &self,
+        range: Range<usize>,
+    ) -> impl Iterator<Item = Result<KeyPair, Error>> + '_ {
+        range.map(|n| self.from_path_index(n))
+    }
+
+    /// Derive the accounts `m/44'/148'/{n}'` for every `n` in `range`
+    pub fn derive_range(&self, range: Range<usize>) -> Result<Vec<KeyPair>, Error> {
+        self.accounts(range).collect()
+    }
+
+    /// Like [`derive_range`](Self::derive_range), deriving accounts on the
+    /// rayon thread pool
+    #[cfg(feature = "rayon")]
+    pub fn par_derive_range(&self, range: Range<usize>) -> Result<Vec<KeyPair>, Error> {
+        use rayon::prelude::*;
+        range
+            .into_par_iter()
+            .map(|n| self.from_path_index(n))
+            .collect()
+    }
+
+    /// Search `m/44'/148'/{n}'` for `n` in `0..=max_index` for `public_key`,
+    /// returning the index and path of the first match
+    pub fn find_account(
+        &self,
+        public_key: &PublicKey,
+        max_index: usize,
+    ) -> Result<Option<(usize, DerivationPath)>, Error> {
+        for n in 0..=max_index {
+            if self.from_path_index(n)?.public() == *public_key {
+                let path = DerivationPath::stellar_account(n as u32)?;
+                return Ok(Some((n, path)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Extended key of the `m/44'/148'` node
+    pub fn extended_key(&self) -> ExtendedKey {
+        ExtendedKey::new(self.node.clone(), DerivationPath::stellar())
+    }
+
+    /// Generate key pair from path `m/44'/148'`.
+    pub fn empty_key(&self) -> KeyPair {
+        KeyPair {
+            private_key: *self.node.key(),
+        }
+    }
+}
+
+impl std::fmt::Debug for DerivedRoot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("DerivedRoot([REDACTED])")
+    }
+}
@@ -0,0 +1,135 @@
+//! Discovery of the SEP-5 accounts of a seed phrase which exist on the
+//! network, to restore a wallet.
+//!
+//! Like BIP-44 account discovery, accounts are derived in order until
+//! `gap_limit` consecutive accounts do not exist.
+
+use std::collections::HashSet;
+
+use stellar_strkey::ed25519::PublicKey;
+
+use crate::{derived_root::DerivedRoot, error::Error, key_pair::KeyPair};
+
+/// Gap limit of BIP-44 account discovery
+pub const DEFAULT_GAP_LIMIT: usize = 20;
+
+/// Source of truth on which accounts exist
+pub trait AccountOracle {
+    fn account_exists(&self, public_key: &PublicKey) -> Result<bool, Error>;
+}
+
+/// Oracle of a fixed set of accounts, e.g. for tests or an offline snapshot
+#[derive(Clone, Debug, Default)]
+pub struct MemoryOracle {
+    accounts: HashSet<PublicKey>,
+}
+
+impl MemoryOracle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, public_key: PublicKey) {
+        self.accounts.insert(public_key);
+    }
+
+    /// Oracle of a JSON array of account addresses (G...), with the `json`
+    /// feature
+    #[cfg(feature = "json")]
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let addresses: Vec<String> = serde_json::from_str(json)?;
+        addresses
+            .iter()
+            .map(|address| PublicKey::from_string(address).map_err(|_| Error::InvalidPublicKey))
+            .collect()
+    }
+
+    /// Read [`from_json`](Self::from_json) from the file at `path`
+    #[cfg(feature = "json")]
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self, Error> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+}
+
+impl FromIterator<PublicKey> for MemoryOracle {
+    fn from_iter<I: IntoIterator<Item = PublicKey>>(iter: I) -> Self {
+        Self {
+            accounts: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl AccountOracle for MemoryOracle {
+    fn account_exists(&self, public_key: &PublicKey) -> Result<bool, Error> {
+        Ok(self.accounts.contains(public_key))
+    }
+}
+
+/// Oracle querying a Horizon server, with the `horizon` feature
+#[cfg(feature = "horizon")]
+#[derive(Clone, Debug)]
+pub struct HorizonOracle {
+    url: String,
+    agent: ureq::Agent,
+}
+
+#[cfg(feature = "horizon")]
+impl HorizonOracle {
+    /// Horizon server of the public Stellar network run by SDF
+    pub const PUBLIC: &'static str = "https://horizon.stellar.org";
+    /// Horizon server of the Stellar test network run by SDF
+    pub const TEST: &'static str = "https://horizon-testnet.stellar.org";
+
+    /// Oracle of the Horizon server at `url`
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.trim_end_matches('/').to_string(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(std::time::Duration::from_secs(30))
+                .build(),
+        }
+    }
+}
+
+#[cfg(feature = "horizon")]
+impl AccountOracle for HorizonOracle {
+    fn account_exists(&self, public_key: &PublicKey) -> Result<bool, Error> {
+        let url = format!("{}/accounts/{}", self.url, public_key.to_string().as_str());
+        match self.agent.get(&url).call() {
+            Ok(_) => Ok(true),
+            Err(ureq::Error::Status(404, _)) => Ok(false),
+            Err(e) => Err(Error::Oracle(Box::new(e))),
+        }
+    }
+}
+
+impl DerivedRoot {
+    /// Accounts `m/44'/148'/{n}'` which exist according to `oracle`, with
+    /// their index, stopping after `gap_limit` consecutive missing accounts.
+    ///
+    /// Fails with [`Error::ZeroGapLimit`] when `gap_limit` is 0.
+    pub fn discover_accounts(
+        &self,
+        oracle: &impl AccountOracle,
+        gap_limit: usize,
+    ) -> Result<Vec<(usize, KeyPair)>, Error> {
+        if gap_limit == 0 {
+            return Err(Error::ZeroGapLimit);
+        }
+        let mut found = Vec::new();
+        let mut missing = 0;
+        for (n, key_pair) in self.accounts(0..usize::MAX).enumerate() {
+            if missing >= gap_limit {
+                break;
+            }
+            let key_pair = key_pair?;
+            if oracle.account_exists(&key_pair.public())? {
+                found.push((n, key_pair));
+                missing = 0;
+            } else {
+                missing += 1;
+            }
+        }
+        Ok(found)
+    }
+}
@@ -1,11 +1,253 @@
+use crate::validation::ValidationReport;
+
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error("Invalid index provided for path {path}")]
     InvalidIndex { path: String },
 
+    #[error("Key derivation failed for path {path}: {source}")]
+    Derivation {
+        path: String,
+        #[source]
+        source: ows_signer::hd::HdError,
+    },
+
+    #[error(transparent)]
+    InvalidPath(#[from] PathError),
+
     #[error(transparent)]
-    Bip32(#[from] bip39::ErrorKind),
+    Bip39(#[from] bip39::ErrorKind),
+
+    #[error("Invalid seed phrase: {0}")]
+    InvalidSeedPhrase(Box<ValidationReport>),
+
+    #[error("Curve {0:?} is not supported, expected Ed25519")]
+    UnsupportedCurve(ows_signer::Curve),
+
+    #[error("Invalid ed25519 public key")]
+    InvalidPublicKey,
+
+    #[error("Invalid secret key")]
+    InvalidSecretKey,
+
+    #[error("Invalid extended key")]
+    InvalidExtendedKey,
+
+    #[error("Invalid muxed account address {0:?}")]
+    InvalidMuxedAccount(String),
+
+    #[error("Signature verification failed")]
+    InvalidSignature,
+
+    #[error("Invalid signature length {0}, expected 64 bytes")]
+    InvalidSignatureLength(usize),
+
+    #[error("Invalid length {length}, expected {min} to {max}")]
+    InvalidLength {
+        length: usize,
+        min: usize,
+        max: usize,
+    },
+
+    #[error("Unsupported BIP-85 word count {0}, expected 12, 18 or 24")]
+    UnsupportedWordCount(usize),
+
+    #[error(transparent)]
+    Slip39(#[from] Slip39Error),
+
+    #[error(transparent)]
+    Keystore(#[from] KeystoreError),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[cfg(feature = "stellar-xdr")]
+    #[error(transparent)]
+    Xdr(#[from] stellar_xdr::curr::Error),
+
+    #[cfg(feature = "stellar-xdr")]
+    #[error("Envelope already has the maximum of 20 signatures")]
+    TooManySignatures,
+
+    #[cfg(feature = "stellar-xdr")]
+    #[error("Authorization entry is not for the account of this key")]
+    WrongAuthorizationAddress,
+
+    #[cfg(feature = "stellar-xdr")]
+    #[error("Invalid SEP-10 challenge: {0}")]
+    InvalidChallenge(#[from] ChallengeError),
+
+    #[error(transparent)]
+    Base64(#[from] base64::DecodeError),
+
+    #[error("Account oracle failed: {0}")]
+    Oracle(Box<dyn std::error::Error + Send + Sync>),
+
+    #[cfg(feature = "json")]
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error("Gap limit must be at least 1")]
+    ZeroGapLimit,
+
+    #[error("Cancelled")]
+    Cancelled,
+
+    #[error("Too many missing words to recover: {0}")]
+    TooManyMissingWords(usize),
 
     #[error("Unknown error from bip32")]
     Unknown,
 }
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum PathError {
+    #[error("Derivation path {0:?} must start with \"m\"")]
+    MissingRoot(String),
+
+    #[error("Invalid segment {position} ({segment:?}) in derivation path {path:?}: {reason}")]
+    InvalidSegment {
+        path: String,
+        position: usize,
+        segment: String,
+        reason: SegmentError,
+    },
+
+    #[error("Derivation path {0:?} is deeper than 255 segments")]
+    TooDeep(String),
+}
+
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SegmentError {
+    #[error("segment is empty")]
+    Empty,
+
+    #[error("index is not a number")]
+    NotANumber,
+
+    #[error("index must be below 2^31")]
+    OutOfRange,
+
+    #[error("ed25519 can only derive hardened indexes, add a trailing '")]
+    NotHardened,
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum Slip39Error {
+    #[error("Unknown SLIP-39 word {0:?}")]
+    InvalidWord(String),
+
+    #[error("Invalid SLIP-39 share length of {0} words")]
+    InvalidLength(usize),
+
+    #[error("Invalid SLIP-39 share checksum")]
+    InvalidChecksum,
+
+    #[error("Invalid SLIP-39 share padding")]
+    InvalidPadding,
+
+    #[error("Invalid master secret length {0}, expected an even number of at least 16 bytes")]
+    InvalidMasterSecretLength(usize),
+
+    #[error("Group threshold must be between 1 and the number of groups")]
+    InvalidGroupThreshold,
+
+    #[error("Group index must be below the number of groups")]
+    InvalidGroupIndex,
+
+    #[error(
+        "Invalid group, expected 1 to 16 shares with a threshold of at least 2 for several shares"
+    )]
+    InvalidGroup,
+
+    #[error("Shares do not belong to the same backup")]
+    MismatchedShares,
+
+    #[error("Two different shares have the same member index")]
+    DuplicateMemberIndex,
+
+    #[error("Not enough shares to recover the secret")]
+    InsufficientShares,
+
+    #[error("Invalid digest of the recovered secret")]
+    InvalidDigest,
+
+    #[error("SLIP-39 passphrases must be printable ASCII")]
+    InvalidPassphrase,
+
+    #[error("No shares provided")]
+    NoShares,
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum KeystoreError {
+    #[error("Wrong keystore password")]
+    WrongPassword,
+
+    #[error("Keystore is corrupted")]
+    Corrupted,
+
+    #[error("Unsupported keystore version {0}")]
+    UnsupportedVersion(u32),
+
+    #[error("Unsupported keystore algorithm {0:?}")]
+    UnsupportedAlgorithm(String),
+
+    #[error("Invalid keystore KDF parameters")]
+    InvalidKdfParams,
+}
+
+#[cfg(feature = "stellar-xdr")]
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChallengeError {
+    #[error("envelope is not a V1 transaction envelope")]
+    NotV1Envelope,
+
+    #[error("source account is not the server account")]
+    WrongSourceAccount,
+
+    #[error("sequence number is not 0")]
+    NonZeroSequence,
+
+    #[error("memo must be empty or an ID")]
+    InvalidMemo,
+
+    #[error("time bounds are missing")]
+    MissingTimeBounds,
+
+    #[error("challenge has expired or is not valid yet")]
+    Expired,
+
+    #[error("challenge has no operations")]
+    NoOperations,
+
+    #[error("operations must all be manage data operations")]
+    NotManageData,
+
+    #[error("first operation source is not the client account")]
+    WrongClientAccount,
+
+    #[error("first operation is not for the home domain")]
+    WrongHomeDomain,
+
+    #[error("nonce must be 64 bytes long")]
+    InvalidNonce,
+
+    #[error("operation source is not the server account")]
+    UnexpectedOperationSource,
+
+    #[error("web_auth_domain does not match")]
+    WrongWebAuthDomain,
+
+    #[error("client_domain operation was not requested")]
+    UnexpectedClientDomain,
+
+    #[error("client_domain operation does not match the client domain")]
+    WrongClientDomain,
+
+    #[error("client_domain operation is missing")]
+    MissingClientDomain,
+
+    #[error("challenge must be signed by the server account only")]
+    MissingServerSignature,
+}
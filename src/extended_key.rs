@@ -0,0 +1,151 @@
+//! SLIP-10 extended keys, to hand a sub-tree of accounts to a service without
+//! the seed phrase.
+//!
+//! The text form is the base58check encoding of
+//! `version (1) | depth (1) | index (4) * depth | chain code (32) | key (32)`,
+//! with indexes in big endian and without the hardened bit. Unlike BIP-32
+//! extended keys it keeps the whole path, since ed25519 nodes have no
+//! public parent fingerprint to check.
+
+use std::str::FromStr;
+
+use stellar_strkey::ed25519::PublicKey;
+use zeroize::Zeroizing;
+
+use crate::{
+    derivation_path::DerivationPath,
+    error::{Error, PathError},
+    key_pair::KeyPair,
+    slip10::Node,
+};
+
+/// Version byte of the text form
+const VERSION: u8 = 1;
+
+/// A SLIP-10 ed25519 node with its path, zeroized on drop
+#[derive(Clone)]
+pub struct ExtendedKey {
+    node: Node,
+    path: DerivationPath,
+}
+
+impl ExtendedKey {
+    /// Deepest path that can be encoded
+    pub const MAX_DEPTH: usize = 255;
+
+    /// Master node `m` of a BIP-39 seed
+    pub fn from_seed(seed: &[u8]) -> Self {
+        Self {
+            node: Node::master(seed),
+            path: DerivationPath::default(),
+        }
+    }
+
+    /// Node at `path` under the master node of a BIP-39 seed
+    pub fn from_seed_path(seed: &[u8], path: &DerivationPath) -> Result<Self, Error> {
+        Self::from_seed(seed).derive_path(path)
+    }
+
+    pub(crate) fn new(node: Node, path: DerivationPath) -> Self {
+        Self { node, path }
+    }
+
+    /// Absolute path of this node
+    pub fn path(&self) -> &DerivationPath {
+        &self.path
+    }
+
+    pub fn chain_code(&self) -> &[u8; 32] {
+        self.node.chain_code()
+    }
+
+    /// Key pair of this node
+    pub fn key_pair(&self) -> KeyPair {
+        KeyPair::from_bytes(*self.node.key())
+    }
+
+    pub fn public(&self) -> PublicKey {
+        self.key_pair().public()
+    }
+
+    /// Hardened child at `index`
+    pub fn derive(&self, index: u32) -> Result<Self, Error> {
+        let path = self.path.child(index)?;
+        if path.depth() > Self::MAX_DEPTH {
+            return Err(PathError::TooDeep(path.to_string()).into());
+        }
+        Ok(Self {
+            node: self.node.derive(index),
+            path,
+        })
+    }
+
+    /// Descendant at `path` relative to this node, e.g. `m/0'/1'`
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self, Error> {
+        path.indexes()
+            .iter()
+            .try_fold(self.clone(), |node, index| node.derive(*index))
+    }
+
+    /// Base58check text form, see the [module documentation](self)
+    pub fn to_base58(&self) -> Zeroizing<String> {
+        let indexes = self.path.indexes();
+        let mut bytes = Zeroizing::new(Vec::with_capacity(2 + 4 * indexes.len() + 64));
+        bytes.push(VERSION);
+        bytes.push(indexes.len() as u8);
+        for index in indexes {
+            bytes.extend_from_slice(&index.to_be_bytes());
+        }
+        bytes.extend_from_slice(self.node.chain_code());
+        bytes.extend_from_slice(self.node.key());
+        Zeroizing::new(bs58::encode(&bytes[..]).with_check().into_string())
+    }
+
+    /// Parse the text form of [`to_base58`](Self::to_base58)
+    pub fn from_base58(s: &str) -> Result<Self, Error> {
+        let bytes = Zeroizing::new(
+            bs58::decode(s)
+                .with_check(None)
+                .into_vec()
+                .map_err(|_| Error::InvalidExtendedKey)?,
+        );
+        let (&[version, depth], rest) = bytes.split_at(2.min(bytes.len())) else {
+            return Err(Error::InvalidExtendedKey);
+        };
+        let depth = usize::from(depth);
+        if version != VERSION || rest.len() != 4 * depth + 64 {
+            return Err(Error::InvalidExtendedKey);
+        }
+        let (indexes, node) = rest.split_at(4 * depth);
+        let indexes: Vec<u32> = indexes
+            .chunks_exact(4)
+            .map(|index| u32::from_be_bytes(index.try_into().expect("chunk has 4 bytes")))
+            .collect();
+        let path = DerivationPath::new(&indexes).map_err(|_| Error::InvalidExtendedKey)?;
+        let (chain_code, key) = node.split_at(32);
+        Ok(Self {
+            node: Node::from_parts(
+                key.try_into().expect("key has 32 bytes"),
+                chain_code.try_into().expect("chain code has 32 bytes"),
+            ),
+            path,
+        })
+    }
+}
+
+impl FromStr for ExtendedKey {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_base58(s)
+    }
+}
+
+impl std::fmt::Debug for ExtendedKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExtendedKey")
+            .field("path", &self.path.to_string())
+            .field("public_key", &self.public().to_string())
+            .field("private_key", &"[REDACTED]")
+            .finish()
+    }
+}
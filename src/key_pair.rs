@@ -0,0 +1,192 @@
+use std::{fmt::Display, str::FromStr};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use ed25519_dalek::{Signer, SigningKey, Verifier, VerifyingKey};
+use sha2::{Digest, Sha256};
+use stellar_strkey::ed25519::{MuxedAccount, PrivateKey, PublicKey};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::error::Error;
+
+/// Prefix of SEP-53 signed messages
+pub const MESSAGE_PREFIX: &str = "Stellar Signed Message:\n";
+
+/// An ed25519 key pair whose private key is zeroized on drop
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct KeyPair {
+    pub(crate) private_key: [u8; 32],
+}
+
+impl KeyPair {
+    /// Key pair of a raw ed25519 private key, e.g. a non-HD account
+    pub fn from_bytes(private_key: [u8; 32]) -> Self {
+        Self { private_key }
+    }
+
+    /// Key pair of a Stellar secret seed (S...)
+    pub fn from_secret(secret: &str) -> Result<Self, Error> {
+        let mut private_key =
+            PrivateKey::from_string(secret).map_err(|_| Error::InvalidSecretKey)?;
+        let key_pair = Self::from_bytes(private_key.0);
+        private_key.0.zeroize();
+        Ok(key_pair)
+    }
+
+    pub fn public(&self) -> PublicKey {
+        PublicKey(self.signing_key().verifying_key().to_bytes())
+    }
+
+    /// Copy of the private key; unlike `KeyPair` it is not wiped on drop
+    pub fn private(&self) -> PrivateKey {
+        PrivateKey(self.private_key)
+    }
+
+    /// Muxed account (M-address) of this key's account with `id`, used to
+    /// tell apart sub-accounts sharing one Stellar account
+    pub fn muxed(&self, id: u64) -> MuxedAccount {
+        MuxedAccount {
+            ed25519: self.public().0,
+            id,
+        }
+    }
+
+    /// Sign `message` with the ed25519 private key
+    pub fn sign(&self, message: &[u8]) -> Signature {
+        Signature(self.signing_key().sign(message).to_bytes())
+    }
+
+    /// Verify that `signature` was produced by this key pair over `message`
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> Result<(), Error> {
+        verify(&self.public(), message, signature)
+    }
+
+    /// Sign an off-chain `message` following SEP-53
+    pub fn sign_message(&self, message: &[u8]) -> Signature {
+        self.sign(&message_hash(message))
+    }
+
+    /// Verify a SEP-53 `signature` of `message` by this key pair
+    pub fn verify_message(&self, message: &[u8], signature: &Signature) -> Result<(), Error> {
+        self.public().verify_message(message, signature)
+    }
+
+    fn signing_key(&self) -> SigningKey {
+        SigningKey::from_bytes(&self.private_key)
+    }
+}
+
+impl std::fmt::Debug for KeyPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeyPair")
+            .field("public_key", &self.public().to_string())
+            .field("private_key", &"[REDACTED]")
+            .finish()
+    }
+}
+
+impl From<PrivateKey> for KeyPair {
+    fn from(private_key: PrivateKey) -> Self {
+        Self::from_bytes(private_key.0)
+    }
+}
+
+/// Secret seed (S...) of the key pair, parsed back by [`FromStr`]
+impl Display for KeyPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.private().to_string().as_str())
+    }
+}
+
+impl FromStr for KeyPair {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_secret(s)
+    }
+}
+
+/// Verify an ed25519 `signature` over `message` using only the public key
+pub fn verify(public_key: &PublicKey, message: &[u8], signature: &Signature) -> Result<(), Error> {
+    let verifying_key =
+        VerifyingKey::from_bytes(&public_key.0).map_err(|_| Error::InvalidPublicKey)?;
+    verifying_key
+        .verify(message, &ed25519_dalek::Signature::from_bytes(&signature.0))
+        .map_err(|_| Error::InvalidSignature)
+}
+
+/// Split an M-address into its base account and ID
+pub fn parse_muxed(address: &str) -> Result<(PublicKey, u64), Error> {
+    let muxed = MuxedAccount::from_string(address)
+        .map_err(|_| Error::InvalidMuxedAccount(address.to_string()))?;
+    Ok((PublicKey(muxed.ed25519), muxed.id))
+}
+
+/// SHA-256 hash of `message` with the SEP-53 prefix, which is what gets
+/// signed
+pub fn message_hash(message: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(MESSAGE_PREFIX);
+    hasher.update(message);
+    hasher.finalize().into()
+}
+
+/// Verification of SEP-53 message signatures with only a public key
+pub trait VerifyMessage {
+    fn verify_message(&self, message: &[u8], signature: &Signature) -> Result<(), Error>;
+}
+
+impl VerifyMessage for PublicKey {
+    fn verify_message(&self, message: &[u8], signature: &Signature) -> Result<(), Error> {
+        verify(self, &message_hash(message), signature)
+    }
+}
+
+/// Detached ed25519 signature
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature(pub [u8; 64]);
+
+impl Signature {
+    pub fn to_bytes(&self) -> [u8; 64] {
+        self.0
+    }
+
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        let bytes = bytes
+            .try_into()
+            .map_err(|_| Error::InvalidSignatureLength(bytes.len()))?;
+        Ok(Self(bytes))
+    }
+
+    /// Standard (padded) base64 encoding of the signature bytes
+    pub fn to_base64(&self) -> String {
+        STANDARD.encode(self.0)
+    }
+
+    pub fn from_base64(s: &str) -> Result<Self, Error> {
+        Self::from_slice(&STANDARD.decode(s)?)
+    }
+}
+
+impl From<[u8; 64]> for Signature {
+    fn from(bytes: [u8; 64]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl AsRef<[u8]> for Signature {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Display for Signature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_base64())
+    }
+}
+
+impl FromStr for Signature {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_base64(s)
+    }
+}
@@ -0,0 +1,347 @@
+//! Password encrypted keystore files for seed phrases.
+//!
+//! A keystore is a JSON document holding the seed phrase, its language and
+//! an optional derivation path hint, encrypted with XChaCha20-Poly1305
+//! under a key derived from the password with Argon2id:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "kdf": { "algorithm": "argon2id", "memory_kib": 19456, "iterations": 2, "parallelism": 1, "salt": "..." },
+//!   "cipher": { "algorithm": "xchacha20-poly1305", "nonce": "..." },
+//!   "checksum": "...",
+//!   "ciphertext": "..."
+//! }
+//! ```
+//!
+//! The header (version, KDF and cipher) is authenticated as associated data
+//! of the ciphertext. The checksum is an unkeyed SHA-256 digest of the header
+//! and ciphertext: it only tells accidental damage from a wrong password, and
+//! deliberate tampering is caught by the AEAD tag, reported as a wrong
+//! password.
+//!
+//! The KDF parameters are read from the file, so stronger parameters only
+//! apply to keystores saved after they change: load and save again to
+//! upgrade a file.
+
+use std::{fs, io::Write, path::Path};
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chacha20poly1305::{
+    aead::{self, Aead, KeyInit},
+    XChaCha20Poly1305, XNonce,
+};
+use rand::{rngs::OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+use crate::{
+    derivation_path::DerivationPath,
+    error::{Error, KeystoreError},
+    language,
+    seed_phrase::SeedPhrase,
+};
+
+/// Version written by [`Keystore::encrypt`]
+pub const VERSION: u32 = 1;
+
+const KDF_ALGORITHM: &str = "argon2id";
+const CIPHER_ALGORITHM: &str = "xchacha20-poly1305";
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 24;
+
+/// A seed phrase with the path of the account it is used for
+#[derive(Clone, Debug)]
+pub struct Keystore {
+    pub seed_phrase: SeedPhrase,
+    /// Derivation path to use with the seed phrase, if known
+    pub hint: Option<DerivationPath>,
+}
+
+/// Argon2id cost parameters, stored in every keystore
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KdfParams {
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+impl KdfParams {
+    /// Largest memory cost accepted, 1 GiB
+    pub const MAX_MEMORY_KIB: u32 = 1024 * 1024;
+    /// Largest number of iterations accepted
+    pub const MAX_ITERATIONS: u32 = 64;
+    /// Largest number of lanes accepted
+    pub const MAX_PARALLELISM: u32 = 16;
+
+    /// Whether the parameters are within the ceilings, so that a keystore
+    /// cannot make loading it exhaust memory or run for hours
+    fn is_bounded(&self) -> bool {
+        self.memory_kib <= Self::MAX_MEMORY_KIB
+            && self.iterations <= Self::MAX_ITERATIONS
+            && self.parallelism <= Self::MAX_PARALLELISM
+    }
+}
+
+impl Default for KdfParams {
+    /// The parameters recommended by OWASP: 19 MiB, 2 iterations, 1 lane
+    fn default() -> Self {
+        Self {
+            memory_kib: 19 * 1024,
+            iterations: 2,
+            parallelism: 1,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct File {
+    version: u32,
+    kdf: Kdf,
+    cipher: Cipher,
+    checksum: String,
+    ciphertext: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Kdf {
+    algorithm: String,
+    #[serde(flatten)]
+    params: KdfParams,
+    salt: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Cipher {
+    algorithm: String,
+    nonce: String,
+}
+
+#[derive(Deserialize)]
+struct Header {
+    version: u32,
+}
+
+/// Encrypted content of a keystore
+#[derive(Serialize, Deserialize, Zeroize, ZeroizeOnDrop)]
+struct Payload {
+    phrase: String,
+    language: String,
+    hint: Option<String>,
+}
+
+impl Keystore {
+    pub fn new(seed_phrase: SeedPhrase) -> Self {
+        Self {
+            seed_phrase,
+            hint: None,
+        }
+    }
+
+    pub fn with_hint(mut self, hint: DerivationPath) -> Self {
+        self.hint = Some(hint);
+        self
+    }
+
+    /// Encrypt into a keystore document with the default [`KdfParams`]
+    pub fn encrypt(&self, password: &str) -> Result<String, Error> {
+        self.encrypt_with(password, KdfParams::default())
+    }
+
+    /// Encrypt into a keystore document
+    pub fn encrypt_with(&self, password: &str, params: KdfParams) -> Result<String, Error> {
+        let mut salt = [0; SALT_LENGTH];
+        let mut nonce = [0; NONCE_LENGTH];
+        OsRng.fill_bytes(&mut salt);
+        OsRng.fill_bytes(&mut nonce);
+        let key = derive_key(password, &salt, params)?;
+        let aad = associated_data(KDF_ALGORITHM, params, &salt, CIPHER_ALGORITHM, &nonce);
+
+        let payload = Payload {
+            phrase: self.seed_phrase.phrase().to_string(),
+            language: language::name(self.seed_phrase.language()).to_string(),
+            hint: self.hint.as_ref().map(ToString::to_string),
+        };
+        let mut plaintext = Zeroizing::new(Vec::with_capacity(1024));
+        serde_json::to_writer(&mut *plaintext, &payload).expect("payload serializes to JSON");
+        let ciphertext = cipher(&key)
+            .encrypt(
+                XNonce::from_slice(&nonce),
+                aead::Payload {
+                    msg: plaintext.as_slice(),
+                    aad: &aad,
+                },
+            )
+            .expect("payload fits in a single message");
+
+        let file = File {
+            version: VERSION,
+            kdf: Kdf {
+                algorithm: KDF_ALGORITHM.to_string(),
+                params,
+                salt: STANDARD.encode(salt),
+            },
+            cipher: Cipher {
+                algorithm: CIPHER_ALGORITHM.to_string(),
+                nonce: STANDARD.encode(nonce),
+            },
+            checksum: STANDARD.encode(checksum(&aad, &ciphertext)),
+            ciphertext: STANDARD.encode(ciphertext),
+        };
+        Ok(serde_json::to_string_pretty(&file).expect("keystore serializes to JSON"))
+    }
+
+    /// Decrypt a keystore document.
+    ///
+    /// Returns [`KeystoreError::WrongPassword`] when the password does not
+    /// match, and [`KeystoreError::Corrupted`] when the document was
+    /// modified or truncated.
+    pub fn decrypt(document: &str, password: &str) -> Result<Self, Error> {
+        let header: Header =
+            serde_json::from_str(document).map_err(|_| KeystoreError::Corrupted)?;
+        if header.version != VERSION {
+            return Err(KeystoreError::UnsupportedVersion(header.version).into());
+        }
+        let file: File = serde_json::from_str(document).map_err(|_| KeystoreError::Corrupted)?;
+        for (algorithm, supported) in [
+            (&file.kdf.algorithm, KDF_ALGORITHM),
+            (&file.cipher.algorithm, CIPHER_ALGORITHM),
+        ] {
+            if algorithm != supported {
+                return Err(KeystoreError::UnsupportedAlgorithm(algorithm.clone()).into());
+            }
+        }
+        if !file.kdf.params.is_bounded() {
+            return Err(KeystoreError::InvalidKdfParams.into());
+        }
+        let salt = decode(&file.kdf.salt)?;
+        let nonce = decode(&file.cipher.nonce)?;
+        if nonce.len() != NONCE_LENGTH {
+            return Err(KeystoreError::Corrupted.into());
+        }
+        let ciphertext = decode(&file.ciphertext)?;
+        let aad = associated_data(
+            &file.kdf.algorithm,
+            file.kdf.params,
+            &salt,
+            &file.cipher.algorithm,
+            &nonce,
+        );
+        if decode(&file.checksum)? != checksum(&aad, &ciphertext) {
+            return Err(KeystoreError::Corrupted.into());
+        }
+        let key = derive_key(password, &salt, file.kdf.params)?;
+        let plaintext = Zeroizing::new(
+            cipher(&key)
+                .decrypt(
+                    XNonce::from_slice(&nonce),
+                    aead::Payload {
+                        msg: &ciphertext,
+                        aad: &aad,
+                    },
+                )
+                .map_err(|_| KeystoreError::WrongPassword)?,
+        );
+        let payload: Payload =
+            serde_json::from_slice(&plaintext).map_err(|_| KeystoreError::Corrupted)?;
+        let language = language::from_name(&payload.language).ok_or(KeystoreError::Corrupted)?;
+        let hint = payload
+            .hint
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+            .map_err(|_| KeystoreError::Corrupted)?;
+        Ok(Self {
+            seed_phrase: SeedPhrase::from_seed_phrase_in(&payload.phrase, language)
+                .map_err(|_| KeystoreError::Corrupted)?,
+            hint,
+        })
+    }
+
+    /// Encrypt and write to `path`, readable by the owner only on Unix
+    pub fn save(&self, path: impl AsRef<Path>, password: &str) -> Result<(), Error> {
+        let document = self.encrypt(password)?;
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = options.open(path)?;
+        // the mode only applies to new files
+        #[cfg(unix)]
+        file.set_permissions(std::os::unix::fs::PermissionsExt::from_mode(0o600))?;
+        file.write_all(document.as_bytes())?;
+        Ok(())
+    }
+
+    /// Read and decrypt the keystore at `path`
+    pub fn load(path: impl AsRef<Path>, password: &str) -> Result<Self, Error> {
+        Self::decrypt(&fs::read_to_string(path)?, password)
+    }
+}
+
+fn derive_key(
+    password: &str,
+    salt: &[u8],
+    params: KdfParams,
+) -> Result<Zeroizing<[u8; 32]>, Error> {
+    if !params.is_bounded() {
+        return Err(KeystoreError::InvalidKdfParams.into());
+    }
+    let params = Params::new(
+        params.memory_kib,
+        params.iterations,
+        params.parallelism,
+        Some(32),
+    )
+    .map_err(|_| KeystoreError::InvalidKdfParams)?;
+    let mut key = Zeroizing::new([0; 32]);
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(password.as_bytes(), salt, &mut *key)
+        .map_err(|_| KeystoreError::InvalidKdfParams)?;
+    Ok(key)
+}
+
+fn cipher(key: &[u8; 32]) -> XChaCha20Poly1305 {
+    XChaCha20Poly1305::new(key.into())
+}
+
+/// Canonical encoding of the header, authenticated with the ciphertext:
+/// the version, then the KDF algorithm, parameters and salt, then the cipher
+/// algorithm and nonce, with variable length fields prefixed by their length
+fn associated_data(
+    kdf: &str,
+    params: KdfParams,
+    salt: &[u8],
+    cipher: &str,
+    nonce: &[u8],
+) -> Vec<u8> {
+    let mut aad = VERSION.to_be_bytes().to_vec();
+    let mut push = |field: &[u8]| {
+        aad.extend_from_slice(&(field.len() as u32).to_be_bytes());
+        aad.extend_from_slice(field);
+    };
+    push(kdf.as_bytes());
+    push(&params.memory_kib.to_be_bytes());
+    push(&params.iterations.to_be_bytes());
+    push(&params.parallelism.to_be_bytes());
+    push(salt);
+    push(cipher.as_bytes());
+    push(nonce);
+    aad
+}
+
+/// Digest stored next to the ciphertext to tell a modified document from a
+/// wrong password
+fn checksum(aad: &[u8], ciphertext: &[u8]) -> Vec<u8> {
+    Sha256::new()
+        .chain_update(aad)
+        .chain_update(ciphertext)
+        .finalize()
+        .to_vec()
+}
+
+fn decode(value: &str) -> Result<Vec<u8>, KeystoreError> {
+    STANDARD.decode(value).map_err(|_| KeystoreError::Corrupted)
+}
@@ -0,0 +1,88 @@
+use unicode_normalization::UnicodeNormalization;
+
+pub use bip39::Language;
+
+/// Every BIP-39 wordlist, in the order used to break ties when detecting
+pub const LANGUAGES: [Language; 8] = [
+    Language::English,
+    Language::ChineseSimplified,
+    Language::ChineseTraditional,
+    Language::French,
+    Language::Italian,
+    Language::Japanese,
+    Language::Korean,
+    Language::Spanish,
+];
+
+/// Detect the wordlist a phrase was written with.
+///
+/// Several wordlists share words, so among the languages containing every
+/// word the first one whose checksum is valid wins. If no wordlist contains
+/// every word, the one containing the most words is returned so that errors
+/// point at the right words.
+pub fn detect(phrase: &str) -> Language {
+    let words = normalized_words(phrase);
+    let known = |language: &Language| {
+        let wordmap = language.wordmap();
+        words
+            .iter()
+            .filter(|w| wordmap.get_bits(w).is_some())
+            .count()
+    };
+    let complete = LANGUAGES
+        .into_iter()
+        .filter(|language| known(language) == words.len())
+        .collect::<Vec<_>>();
+    let phrase = words.join(" ");
+    complete
+        .iter()
+        .copied()
+        .find(|language| bip39::Mnemonic::validate(&phrase, *language).is_ok())
+        .or_else(|| complete.first().copied())
+        .unwrap_or_else(|| {
+            LANGUAGES
+                .into_iter()
+                .rev()
+                .max_by_key(known)
+                .unwrap_or_default()
+        })
+}
+
+/// Lowercase name of a wordlist, as used in file formats and on the
+/// command line, for example `chinese-simplified`
+pub fn name(language: Language) -> &'static str {
+    match language {
+        Language::English => "english",
+        Language::ChineseSimplified => "chinese-simplified",
+        Language::ChineseTraditional => "chinese-traditional",
+        Language::French => "french",
+        Language::Italian => "italian",
+        Language::Japanese => "japanese",
+        Language::Korean => "korean",
+        Language::Spanish => "spanish",
+    }
+}
+
+/// Wordlist with the given [`name`], ignoring case
+pub fn from_name(name: &str) -> Option<Language> {
+    LANGUAGES
+        .into_iter()
+        .find(|language| self::name(*language).eq_ignore_ascii_case(name))
+}
+
+/// Separator used when showing a phrase to a user: BIP-39 Japanese
+/// phrases use the ideographic space (U+3000)
+pub fn separator(language: Language) -> &'static str {
+    match language {
+        Language::Japanese => "\u{3000}",
+        _ => " ",
+    }
+}
+
+/// Words of a phrase in NFKD form, as they appear in the wordlists
+pub(crate) fn normalized_words(phrase: &str) -> Vec<String> {
+    phrase
+        .split_whitespace()
+        .map(|w| w.nfkd().collect())
+        .collect()
+}
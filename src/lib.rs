@@ -1,6 +1,41 @@
+pub mod bip85;
+pub mod coin;
+pub mod curve_key;
+pub mod derivation_path;
+pub mod derived_root;
+pub mod discovery;
 pub mod error;
+pub mod extended_key;
+pub mod key_pair;
+#[cfg(feature = "keystore")]
+pub mod keystore;
+pub mod language;
+pub mod path_scheme;
+pub mod recovery;
 pub mod seed_phrase;
+pub mod signer;
+mod slip10;
+pub mod slip39;
+#[cfg(feature = "stellar-xdr")]
+pub mod soroban;
+#[cfg(feature = "stellar-xdr")]
+pub mod transaction;
+pub mod validation;
+#[cfg(feature = "stellar-xdr")]
+pub mod web_auth;
 
-pub use bip39::MnemonicType;
-pub use error::Error;
+pub use bip39::{Language, MnemonicType};
+pub use coin::{AddressFormat, CoinProfile};
+pub use curve_key::{CurveKey, Secp256k1KeyPair};
+pub use derivation_path::{Bip32Path, DerivationPath};
+pub use derived_root::DerivedRoot;
+#[cfg(feature = "horizon")]
+pub use discovery::HorizonOracle;
+pub use discovery::{AccountOracle, MemoryOracle};
+pub use error::{Error, KeystoreError, PathError, Slip39Error};
+pub use extended_key::ExtendedKey;
+pub use key_pair::{KeyPair, Signature, VerifyMessage};
+pub use ows_signer::Curve;
+pub use path_scheme::PathScheme;
 pub use seed_phrase::SeedPhrase;
+pub use validation::ValidationReport;
@@ -0,0 +1,80 @@
+//! Layouts of Stellar derivation paths used by wallets, to find the accounts
+//! of users migrating from wallets which did not follow SEP-5
+
+use stellar_strkey::ed25519::PublicKey;
+
+use crate::{
+    derivation_path::{account_path, display_path, DerivationPath},
+    error::Error,
+    extended_key::ExtendedKey,
+};
+
+/// A layout of Stellar account paths
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PathScheme {
+    /// `m/44'/148'/{n}'`, as in SEP-5 and Ledger and Trezor devices
+    Sep5,
+    /// `m/44'/148'/0'/0'/{n}'`, the full BIP-44 layout with hardened account
+    /// and change
+    Bip44,
+}
+
+impl PathScheme {
+    /// Every scheme, in the order [`discover`] tries them
+    pub const ALL: &'static [Self] = &[Self::Sep5, Self::Bip44];
+
+    /// Scheme named `name`, e.g. `"bip44"`
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find(|scheme| scheme.name().eq_ignore_ascii_case(name))
+            .copied()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Sep5 => "sep5",
+            Self::Bip44 => "bip44",
+        }
+    }
+
+    /// Path of the account `n`
+    pub fn path(&self, n: usize) -> Result<DerivationPath, Error> {
+        account_path(self.prefix(), n, &[])
+    }
+
+    /// Hardened indexes before the account index
+    fn prefix(&self) -> &'static [u32] {
+        match self {
+            Self::Sep5 => &[44, 148],
+            Self::Bip44 => &[44, 148, 0, 0],
+        }
+    }
+}
+
+/// Search the accounts `0..=max_index` of every scheme derived from a BIP-39
+/// `seed` for `public_key`, returning the first scheme and index matching.
+pub fn discover(
+    seed: &[u8],
+    public_key: &PublicKey,
+    max_index: usize,
+) -> Result<Option<(PathScheme, usize)>, Error> {
+    let master = ExtendedKey::from_seed(seed);
+    for scheme in PathScheme::ALL {
+        let prefix = scheme.prefix();
+        let parent = master.derive_path(&DerivationPath::new(prefix)?)?;
+        for n in 0..=max_index {
+            let index = u32::try_from(n)
+                .ok()
+                .filter(|i| *i <= DerivationPath::MAX_INDEX)
+                .ok_or_else(|| Error::InvalidIndex {
+                    path: display_path(prefix, n, &[]),
+                })?;
+            let account = parent.derive(index)?;
+            if account.public() == *public_key {
+                return Ok(Some((*scheme, n)));
+            }
+        }
+    }
+    Ok(None)
+}
@@ -0,0 +1,229 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use sha2::{Digest, Sha256};
+use stellar_strkey::ed25519::PublicKey;
+use zeroize::Zeroizing;
+
+use crate::{
+    derivation_path::DerivationPath,
+    error::Error,
+    language::{self, Language},
+    seed_phrase::SeedPhrase,
+    validation,
+};
+
+/// Marks a missing or unreadable word in a phrase passed to [`Recovery`]
+pub const PLACEHOLDER: &str = "?";
+
+/// Number of candidates checked between two progress reports
+const PROGRESS_INTERVAL: u64 = 1024;
+
+/// Recover a seed phrase with missing words.
+///
+/// Every combination of words for the [`PLACEHOLDER`]s is enumerated and
+/// those with a valid BIP-39 checksum are kept. With a
+/// [`target`](Self::target) only phrases deriving that key through
+/// `m/44'/148'/{n}'` are kept. Each of those runs the BIP-39 seed
+/// derivation, so this can take a long time: use
+/// [`on_progress`](Self::on_progress) and [`cancel_on`](Self::cancel_on) to
+/// run it as a background job.
+///
+/// ```no_run
+/// # use sep5::recovery::Recovery;
+/// let recovered = Recovery::new(
+///     "illness spike ? truth genius clock brain pass fit cave bargain toe",
+/// )?
+/// .run()?;
+/// # Ok::<(), sep5::Error>(())
+/// ```
+pub struct Recovery<'a> {
+    words: Vec<Option<u16>>,
+    language: Language,
+    passphrase: Option<Zeroizing<String>>,
+    target: Option<(PublicKey, usize)>,
+    on_progress: Option<OnProgress<'a>>,
+    cancel: Option<&'a AtomicBool>,
+}
+
+type OnProgress<'a> = Box<dyn FnMut(&Progress) + 'a>;
+
+/// Progress of a running [`Recovery`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Progress {
+    /// Candidates checked so far
+    pub checked: u64,
+    /// Total number of candidates
+    pub total: u64,
+    /// Phrases recovered so far
+    pub matches: usize,
+}
+
+/// A candidate phrase found by [`Recovery::run`]
+#[derive(Debug)]
+pub struct Recovered {
+    pub seed_phrase: SeedPhrase,
+    /// Index and path at which the target key was found, if one was set
+    pub account: Option<(usize, DerivationPath)>,
+}
+
+impl<'a> Recovery<'a> {
+    /// Start a recovery, detecting the wordlist from the readable words
+    pub fn new(phrase: &str) -> Result<Self, Error> {
+        let known = phrase
+            .split_whitespace()
+            .filter(|w| *w != PLACEHOLDER)
+            .collect::<Vec<_>>()
+            .join(" ");
+        Self::new_in(phrase, language::detect(&known))
+    }
+
+    /// Start a recovery of a phrase from the `language` wordlist
+    pub fn new_in(phrase: &str, language: Language) -> Result<Self, Error> {
+        let mut report = validation::validate_in(phrase, language);
+        report.unknown_words.retain(|w| w.word != PLACEHOLDER);
+        if !report.word_count_valid || !report.unknown_words.is_empty() {
+            return Err(Error::InvalidSeedPhrase(Box::new(report)));
+        }
+        let wordmap = language.wordmap();
+        let words = language::normalized_words(phrase)
+            .iter()
+            .map(|w| wordmap.get_bits(w).map(u16::from))
+            .collect();
+        Ok(Self {
+            words,
+            language,
+            passphrase: None,
+            target: None,
+            on_progress: None,
+            cancel: None,
+        })
+    }
+
+    /// BIP-39 passphrase used to derive keys when looking for a target
+    pub fn passphrase(mut self, passphrase: &str) -> Self {
+        self.passphrase = Some(Zeroizing::new(passphrase.to_string()));
+        self
+    }
+
+    /// Only keep phrases deriving `public_key` at `m/44'/148'/{n}'` for `n`
+    /// in `0..=max_index`
+    pub fn target(mut self, public_key: PublicKey, max_index: usize) -> Self {
+        self.target = Some((public_key, max_index));
+        self
+    }
+
+    /// Called periodically while running, and once when done
+    pub fn on_progress(mut self, on_progress: impl FnMut(&Progress) + 'a) -> Self {
+        self.on_progress = Some(Box::new(on_progress));
+        self
+    }
+
+    /// Stop with [`Error::Cancelled`] once `cancel` is set
+    pub fn cancel_on(mut self, cancel: &'a AtomicBool) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+
+    /// Number of combinations that will be checked, if it fits in a `u64`
+    pub fn candidates(&self) -> Option<u64> {
+        u32::try_from(self.missing().len())
+            .ok()
+            .and_then(|missing| 2048u64.checked_pow(missing))
+    }
+
+    /// Fails with [`Error::TooManyMissingWords`] when there are too many
+    /// combinations to enumerate
+    pub fn run(mut self) -> Result<Vec<Recovered>, Error> {
+        let missing = self.missing();
+        let mut progress = Progress {
+            checked: 0,
+            total: self
+                .candidates()
+                .ok_or(Error::TooManyMissingWords(missing.len()))?,
+            matches: 0,
+        };
+        let mut words = Zeroizing::new(
+            self.words
+                .iter()
+                .map(|w| w.unwrap_or(0))
+                .collect::<Vec<_>>(),
+        );
+        let mut recovered = Vec::new();
+        while progress.checked < progress.total {
+            if matches!(self.cancel, Some(cancel) if cancel.load(Ordering::Relaxed)) {
+                return Err(Error::Cancelled);
+            }
+            let mut combination = progress.checked;
+            for position in &missing {
+                words[*position] = (combination % 2048) as u16;
+                combination /= 2048;
+            }
+            if let Some(found) = self.check(&words)? {
+                recovered.push(found);
+                progress.matches += 1;
+            }
+            progress.checked += 1;
+            if progress.checked.is_multiple_of(PROGRESS_INTERVAL) {
+                self.report(&progress);
+            }
+        }
+        if !progress.checked.is_multiple_of(PROGRESS_INTERVAL) {
+            self.report(&progress);
+        }
+        Ok(recovered)
+    }
+
+    fn missing(&self) -> Vec<usize> {
+        (0..self.words.len())
+            .filter(|i| self.words[*i].is_none())
+            .collect()
+    }
+
+    fn report(&mut self, progress: &Progress) {
+        if let Some(on_progress) = self.on_progress.as_mut() {
+            on_progress(progress);
+        }
+    }
+
+    fn check(&self, words: &[u16]) -> Result<Option<Recovered>, Error> {
+        let Some(entropy) = checked_entropy(words) else {
+            return Ok(None);
+        };
+        let seed_phrase = SeedPhrase::from_entropy_in(&entropy, self.language)?;
+        let Some((public_key, max_index)) = &self.target else {
+            return Ok(Some(Recovered {
+                seed_phrase,
+                account: None,
+            }));
+        };
+        let account = seed_phrase.find_account(
+            public_key,
+            self.passphrase.as_deref().map(|p| p.as_str()),
+            *max_index,
+        )?;
+        Ok(account.map(|account| Recovered {
+            seed_phrase,
+            account: Some(account),
+        }))
+    }
+}
+
+/// Entropy encoded by wordlist indexes, if the BIP-39 checksum is valid
+fn checked_entropy(words: &[u16]) -> Option<Zeroizing<Vec<u8>>> {
+    let total_bits = words.len() * 11;
+    let checksum_bits = total_bits / 33;
+    let entropy_bytes = (total_bits - checksum_bits) / 8;
+    let mut bytes = Zeroizing::new(vec![0u8; total_bits.div_ceil(8)]);
+    for (i, word) in words.iter().enumerate() {
+        for bit in 0..11 {
+            if word >> (10 - bit) & 1 == 1 {
+                let position = i * 11 + bit;
+                bytes[position / 8] |= 0x80 >> (position % 8);
+            }
+        }
+    }
+    let checksum = bytes[entropy_bytes] >> (8 - checksum_bits);
+    let expected = Sha256::digest(&bytes[..entropy_bytes])[0] >> (8 - checksum_bits);
+    bytes.truncate(entropy_bytes);
+    (checksum == expected).then_some(bytes)
+}
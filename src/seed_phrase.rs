@@ -1,88 +1,372 @@
-use std::str::FromStr;
+use std::{fmt::Debug, ops::Range, str::FromStr};
 
-use ed25519_dalek::SigningKey;
 use ows_signer::{Curve, HdDeriver};
-use stellar_strkey::ed25519::{PrivateKey, PublicKey};
+use stellar_strkey::ed25519::PublicKey;
+use unicode_normalization::UnicodeNormalization;
+use zeroize::Zeroizing;
 
-use crate::error::Error;
+pub use crate::key_pair::KeyPair;
+use crate::{
+    bip85,
+    coin::CoinProfile,
+    curve_key::{CurveKey, Secp256k1KeyPair},
+    derivation_path::{Bip32Path, DerivationPath},
+    derived_root::DerivedRoot,
+    discovery::AccountOracle,
+    error::Error,
+    extended_key::ExtendedKey,
+    language::{self, Language},
+    path_scheme::{self, PathScheme},
+    slip10::Node,
+    slip39::{self, Group},
+    validation,
+};
 
-pub struct KeyPair {
-    private_key: [u8; 32],
-}
-
-impl KeyPair {
-    pub fn public(&self) -> PublicKey {
-        let signing_key = SigningKey::from_bytes(&self.private_key);
-        PublicKey(signing_key.verifying_key().to_bytes())
-    }
-
-    pub fn private(&self) -> PrivateKey {
-        PrivateKey(self.private_key)
-    }
-}
-
-#[derive(Clone, Debug)]
+/// A BIP-39 mnemonic used to derive SEP-5 keys.
+///
+/// The mnemonic is wiped from memory on drop and is never printed by `Debug`.
+#[derive(Clone)]
 pub struct SeedPhrase {
     pub curve: Curve,
     pub seed_phrase: bip39::Mnemonic,
 }
 
 impl SeedPhrase {
+    /// Seed phrase deriving keys on `curve`
+    pub fn new(seed_phrase: bip39::Mnemonic, curve: Curve) -> Self {
+        Self { curve, seed_phrase }
+    }
+
     pub fn new_ed25519(seed_phrase: bip39::Mnemonic) -> Self {
-        Self {
-            curve: Curve::Ed25519,
-            seed_phrase,
-        }
+        Self::new(seed_phrase, Curve::Ed25519)
+    }
+
+    /// The same seed phrase, deriving keys on `curve` with
+    /// [`derive_key`](Self::derive_key)
+    pub fn with_curve(mut self, curve: Curve) -> Self {
+        self.curve = curve;
+        self
     }
 
-    /// Uses passed entropy to generate the seed phrase
+    /// Uses passed entropy to generate an English seed phrase
     pub fn from_entropy(bytes: &[u8]) -> Result<Self, Error> {
-        let res = bip39::Mnemonic::from_entropy(bytes, bip39::Language::English)?;
+        Self::from_entropy_in(bytes, Language::English)
+    }
+
+    /// Uses passed entropy to generate a seed phrase from the `language` wordlist
+    pub fn from_entropy_in(bytes: &[u8], language: Language) -> Result<Self, Error> {
+        let res = bip39::Mnemonic::from_entropy(bytes, language)?;
         Ok(Self::new_ed25519(res))
     }
 
     /// Creates a `SeedPhrase` using a `seed_phrase`, which is
     /// trimmed and enusures that only one space between words.
+    ///
+    /// The wordlist is detected from the words, see [`language::detect`].
     pub fn from_seed_phrase(seed_phrase: &str) -> Result<Self, Error> {
-        let seed_phrase = seed_phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+        Self::from_seed_phrase_in(seed_phrase, language::detect(seed_phrase))
+    }
 
-        let res = bip39::Mnemonic::from_phrase(&seed_phrase, bip39::Language::English)?;
+    /// Creates a `SeedPhrase` from a phrase using the `language` wordlist.
+    ///
+    /// Words may be separated by any whitespace, including the ideographic
+    /// space used by Japanese phrases, and are NFKD normalized. An invalid
+    /// phrase returns [`Error::InvalidSeedPhrase`] describing every problem.
+    pub fn from_seed_phrase_in(seed_phrase: &str, language: Language) -> Result<Self, Error> {
+        let seed_phrase =
+            Zeroizing::new(seed_phrase.split_whitespace().collect::<Vec<_>>().join(" "));
+
+        let res = bip39::Mnemonic::from_phrase(&seed_phrase, language).map_err(|_| {
+            Error::InvalidSeedPhrase(Box::new(validation::validate_in(&seed_phrase, language)))
+        })?;
         Ok(Self::new_ed25519(res))
     }
 
-    /// Generate a random seed phrase of various lengths
+    /// Generate a random English seed phrase of various lengths
     pub fn random(mtype: bip39::MnemonicType) -> Result<Self, Error> {
-        Ok(Self::new_ed25519(bip39::Mnemonic::new(
-            mtype,
-            bip39::Language::English,
-        )))
+        Self::random_in(mtype, Language::English)
     }
 
-    /// inner string representing the seed phrase
+    /// Generate a random seed phrase of various lengths from the `language` wordlist
+    pub fn random_in(mtype: bip39::MnemonicType, language: Language) -> Result<Self, Error> {
+        Ok(Self::new_ed25519(bip39::Mnemonic::new(mtype, language)))
+    }
+
+    /// Split the phrase's entropy into SLIP-39 share mnemonics, one list per
+    /// group, see [`slip39::split`]. The SLIP-39 `passphrase` encrypts the
+    /// shares and is unrelated to the BIP-39 passphrase used to derive keys.
+    pub fn to_slip39(
+        &self,
+        group_threshold: u8,
+        groups: &[Group],
+        passphrase: &str,
+    ) -> Result<Vec<Vec<String>>, Error> {
+        slip39::split(
+            self.seed_phrase.entropy(),
+            group_threshold,
+            groups,
+            passphrase,
+            1,
+            true,
+        )
+    }
+
+    /// Recombine SLIP-39 shares created by [`to_slip39`](Self::to_slip39)
+    /// into an English seed phrase.
+    ///
+    /// The shares only hold the entropy, and the BIP-39 seed is computed from
+    /// the words, so this derives the same keys only if the original phrase
+    /// was English: see [`from_slip39_in`](Self::from_slip39_in).
+    pub fn from_slip39<S: AsRef<str>>(mnemonics: &[S], passphrase: &str) -> Result<Self, Error> {
+        Self::from_slip39_in(mnemonics, passphrase, Language::English)
+    }
+
+    /// Recombine SLIP-39 shares into a seed phrase from the `language`
+    /// wordlist, which must be the language of the original phrase to derive
+    /// the same keys
+    pub fn from_slip39_in<S: AsRef<str>>(
+        mnemonics: &[S],
+        passphrase: &str,
+        language: Language,
+    ) -> Result<Self, Error> {
+        Self::from_entropy_in(&slip39::combine(mnemonics, passphrase)?, language)
+    }
+
+    /// inner string representing the seed phrase, NFKD normalized and
+    /// separated by single spaces as used to compute the seed
     pub fn phrase(&self) -> &str {
         self.seed_phrase.phrase()
     }
 
-    /// bip39 `Seed` used to derive keys via HD derivation
+    /// The phrase as it should be shown to a user: NFC composed and joined
+    /// with the language's separator
+    pub fn display_phrase(&self) -> String {
+        self.phrase()
+            .split(' ')
+            .map(|w| w.nfc().collect::<String>())
+            .collect::<Vec<_>>()
+            .join(language::separator(self.language()))
+    }
+
+    /// Wordlist of the seed phrase
+    pub fn language(&self) -> Language {
+        self.seed_phrase.language()
+    }
+
+    /// bip39 `Seed` used to derive keys via HD derivation, zeroized on drop
     pub fn to_seed(&self, passphrase: Option<&str>) -> bip39::Seed {
         bip39::Seed::new(&self.seed_phrase, passphrase.unwrap_or_default())
     }
 
     /// Generate a key from a path string, anything after `m/44'/148'`
     pub fn from_path_string(&self, path: &str, passphrase: Option<&str>) -> Result<KeyPair, Error> {
-        let path = format!("m/44'/148'{path}");
-        let secret = HdDeriver::derive(self.to_seed(passphrase).as_bytes(), &path, self.curve)
-            .map_err(|_| Error::InvalidIndex { path: path.clone() })?;
-        let private_key: [u8; 32] = secret
-            .expose()
-            .try_into()
-            .map_err(|_| Error::InvalidIndex { path })?;
-        Ok(KeyPair { private_key })
+        self.from_coin_path_string(&CoinProfile::STELLAR, path, passphrase)
+    }
+
+    /// Generate a key from a path string, anything after `m/44'/{coin_type}'`
+    pub fn from_coin_path_string(
+        &self,
+        coin: &CoinProfile,
+        path: &str,
+        passphrase: Option<&str>,
+    ) -> Result<KeyPair, Error> {
+        self.from_path(&format!("{}{path}", coin.root()).parse()?, passphrase)
+    }
+
+    /// Generate the key of the account `num` of `coin`
+    pub fn coin_account(
+        &self,
+        coin: &CoinProfile,
+        num: usize,
+        passphrase: Option<&str>,
+    ) -> Result<KeyPair, Error> {
+        self.from_path(&coin.account_path(num)?, passphrase)
+    }
+
+    /// Generate a key from a full derivation path.
+    ///
+    /// Only ed25519 seed phrases give Stellar keys, see
+    /// [`derive_key`](Self::derive_key) for other curves.
+    pub fn from_path(
+        &self,
+        path: &DerivationPath,
+        passphrase: Option<&str>,
+    ) -> Result<KeyPair, Error> {
+        if self.curve != Curve::Ed25519 {
+            return Err(Error::UnsupportedCurve(self.curve));
+        }
+        let seed = self.to_seed(passphrase);
+        let node = path
+            .indexes()
+            .iter()
+            .fold(Node::master(seed.as_bytes()), |node, index| {
+                node.derive(*index)
+            });
+        Ok(KeyPair {
+            private_key: *node.key(),
+        })
+    }
+
+    /// Generate a key on the curve of this seed phrase from a full BIP-32
+    /// path, e.g. `m/44'/60'/0'/0/0` for secp256k1.
+    ///
+    /// Ed25519 paths must be hardened only.
+    pub fn derive_key(
+        &self,
+        path: &Bip32Path,
+        passphrase: Option<&str>,
+    ) -> Result<CurveKey, Error> {
+        match self.curve {
+            Curve::Ed25519 => Ok(CurveKey::Ed25519(
+                self.from_path(&DerivationPath::try_from(path)?, passphrase)?,
+            )),
+            Curve::Secp256k1 => {
+                let path = path.to_string();
+                let seed = self.to_seed(passphrase);
+                let secret = HdDeriver::derive(seed.as_bytes(), &path, self.curve)
+                    .map_err(|source| Error::Derivation { path, source })?;
+                let secret = secret.expose();
+                let private_key: [u8; 32] =
+                    secret.try_into().map_err(|_| Error::InvalidLength {
+                        length: secret.len(),
+                        min: 32,
+                        max: 32,
+                    })?;
+                Ok(CurveKey::Secp256k1(Secp256k1KeyPair::from_bytes(
+                    private_key,
+                )?))
+            }
+        }
     }
 
     /// Generate a key from a path index, anything after `m/44'/148'/{num}'`
     pub fn from_path_index(&self, num: usize, passphrase: Option<&str>) -> Result<KeyPair, Error> {
-        self.from_path_string(&format!("/{num}'"), passphrase)
+        self.coin_account(&CoinProfile::STELLAR, num, passphrase)
+    }
+
+    /// Generate the key of the account `num` laid out by `scheme`
+    pub fn from_scheme_index(
+        &self,
+        scheme: PathScheme,
+        num: usize,
+        passphrase: Option<&str>,
+    ) -> Result<KeyPair, Error> {
+        self.from_path(&scheme.path(num)?, passphrase)
+    }
+
+    /// Search the accounts `0..=max_index` of every [`PathScheme`] for
+    /// `public_key`, returning the first scheme and index matching
+    pub fn discover_scheme(
+        &self,
+        public_key: &PublicKey,
+        passphrase: Option<&str>,
+        max_index: usize,
+    ) -> Result<Option<(PathScheme, usize)>, Error> {
+        if self.curve != Curve::Ed25519 {
+            return Err(Error::UnsupportedCurve(self.curve));
+        }
+        path_scheme::discover(self.to_seed(passphrase).as_bytes(), public_key, max_index)
+    }
+
+    /// Derive the `m/44'/148'` node once, to derive many accounts without
+    /// recomputing the seed each time
+    pub fn derived_root(&self, passphrase: Option<&str>) -> Result<DerivedRoot, Error> {
+        if self.curve != Curve::Ed25519 {
+            return Err(Error::UnsupportedCurve(self.curve));
+        }
+        Ok(DerivedRoot::from_seed(self.to_seed(passphrase).as_bytes()))
+    }
+
+    /// Extended key of the node at `path`, to derive its descendants without
+    /// the seed phrase
+    pub fn extended_key(
+        &self,
+        path: &DerivationPath,
+        passphrase: Option<&str>,
+    ) -> Result<ExtendedKey, Error> {
+        if self.curve != Curve::Ed25519 {
+            return Err(Error::UnsupportedCurve(self.curve));
+        }
+        ExtendedKey::from_seed_path(self.to_seed(passphrase).as_bytes(), path)
+    }
+
+    /// Accounts `m/44'/148'/{n}'` which exist according to `oracle`, with
+    /// their index, see [`DerivedRoot::discover_accounts`]
+    pub fn discover_accounts(
+        &self,
+        oracle: &impl AccountOracle,
+        gap_limit: usize,
+        passphrase: Option<&str>,
+    ) -> Result<Vec<(usize, KeyPair)>, Error> {
+        self.derived_root(passphrase)?
+            .discover_accounts(oracle, gap_limit)
+    }
+
+    /// Generate the keys `m/44'/148'/{n}'` for every `n` in `range`
+    pub fn derive_range(
+        &self,
+        range: Range<usize>,
+        passphrase: Option<&str>,
+    ) -> Result<Vec<KeyPair>, Error> {
+        self.derived_root(passphrase)?.derive_range(range)
+    }
+
+    /// Like [`derive_range`](Self::derive_range), deriving keys in parallel
+    #[cfg(feature = "rayon")]
+    pub fn par_derive_range(
+        &self,
+        range: Range<usize>,
+        passphrase: Option<&str>,
+    ) -> Result<Vec<KeyPair>, Error> {
+        self.derived_root(passphrase)?.par_derive_range(range)
+    }
+
+    /// Find the index `n` of the account `m/44'/148'/{n}'` with `public_key`,
+    /// searching up to and including `max_index`
+    pub fn find_account(
+        &self,
+        public_key: &PublicKey,
+        passphrase: Option<&str>,
+        max_index: usize,
+    ) -> Result<Option<(usize, DerivationPath)>, Error> {
+        self.derived_root(passphrase)?
+            .find_account(public_key, max_index)
+    }
+
+    /// BIP-85 child phrase of `word_count` words, see [`bip85::mnemonic`]
+    pub fn bip85_mnemonic(
+        &self,
+        language: Language,
+        word_count: bip39::MnemonicType,
+        index: u32,
+        passphrase: Option<&str>,
+    ) -> Result<SeedPhrase, Error> {
+        bip85::mnemonic(
+            self.to_seed(passphrase).as_bytes(),
+            language,
+            word_count,
+            index,
+        )
+    }
+
+    /// BIP-85 hex entropy of `length` bytes, see [`bip85::hex`]
+    pub fn bip85_hex(
+        &self,
+        length: usize,
+        index: u32,
+        passphrase: Option<&str>,
+    ) -> Result<Zeroizing<String>, Error> {
+        bip85::hex(self.to_seed(passphrase).as_bytes(), length, index)
+    }
+
+    /// BIP-85 password of `length` characters, see [`bip85::password`]
+    pub fn bip85_password(
+        &self,
+        length: usize,
+        index: u32,
+        passphrase: Option<&str>,
+    ) -> Result<Zeroizing<String>, Error> {
+        bip85::password(self.to_seed(passphrase).as_bytes(), length, index)
     }
 
     /// Generate key pair from path `m/44'/148'`.
@@ -91,6 +375,15 @@ impl SeedPhrase {
     }
 }
 
+impl Debug for SeedPhrase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SeedPhrase")
+            .field("curve", &self.curve)
+            .field("seed_phrase", &"[REDACTED]")
+            .finish()
+    }
+}
+
 impl From<SeedPhrase> for bip39::Seed {
     fn from(seed_phrase: SeedPhrase) -> Self {
         seed_phrase.to_seed(None)
@@ -0,0 +1,96 @@
+//! Signer keys other than plain accounts, for multisig setups: signed
+//! payloads (P...), pre-authorized transactions (T...) and hash preimages
+//! (X...)
+
+use sha2::{Digest, Sha256};
+use stellar_strkey::{ed25519::SignedPayload, HashX, PreAuthTx};
+#[cfg(feature = "stellar-xdr")]
+use stellar_xdr::curr::{DecoratedSignature, SignatureHint, TransactionEnvelope};
+
+use crate::{
+    error::Error,
+    key_pair::{KeyPair, Signature},
+};
+
+/// Maximum length of a signed payload
+pub const MAX_PAYLOAD_LENGTH: usize = 64;
+
+/// Hint of a signature for a signed payload signer: the key hint XORed with
+/// the last 4 bytes of the payload, zero padded when shorter
+pub fn payload_signature_hint(signed_payload: &SignedPayload) -> [u8; 4] {
+    let mut payload_hint = [0; 4];
+    let payload = signed_payload.payload.as_slice();
+    let start = payload.len().saturating_sub(4);
+    payload_hint[..payload.len() - start].copy_from_slice(&payload[start..]);
+    let mut hint = [0; 4];
+    for (i, byte) in hint.iter_mut().enumerate() {
+        *byte = signed_payload.ed25519[28 + i] ^ payload_hint[i];
+    }
+    hint
+}
+
+/// Signer key of a transaction with the given hash, which can then be
+/// submitted without other signatures
+pub fn pre_auth_tx(transaction_hash: [u8; 32]) -> PreAuthTx {
+    PreAuthTx(transaction_hash)
+}
+
+/// Signer key of `envelope` on the network with `network_passphrase`, with
+/// the `stellar-xdr` feature
+#[cfg(feature = "stellar-xdr")]
+pub fn pre_auth_tx_envelope(
+    envelope: &TransactionEnvelope,
+    network_passphrase: &str,
+) -> Result<PreAuthTx, Error> {
+    Ok(pre_auth_tx(crate::transaction::hash(
+        envelope,
+        network_passphrase,
+    )?))
+}
+
+/// Signer key satisfied by revealing `preimage` as a signature
+pub fn hash_x(preimage: &[u8]) -> HashX {
+    HashX(Sha256::digest(preimage).into())
+}
+
+impl KeyPair {
+    /// Signed payload signer (P-address) of this key for `payload`, 1 to
+    /// [`MAX_PAYLOAD_LENGTH`] bytes long
+    pub fn signed_payload(&self, payload: &[u8]) -> Result<SignedPayload, Error> {
+        let invalid_length = || Error::InvalidLength {
+            length: payload.len(),
+            min: 1,
+            max: MAX_PAYLOAD_LENGTH,
+        };
+        if payload.is_empty() {
+            return Err(invalid_length());
+        }
+        Ok(SignedPayload {
+            ed25519: self.public().0,
+            payload: heapless::Vec::from_slice(payload).map_err(|_| invalid_length())?,
+        })
+    }
+
+    /// Signature satisfying the signed payload signer of this key for
+    /// `payload`, sent with [`payload_signature_hint`]
+    pub fn sign_payload(&self, payload: &[u8]) -> Result<Signature, Error> {
+        self.signed_payload(payload)?;
+        Ok(self.sign(payload))
+    }
+
+    /// Like [`sign_payload`](Self::sign_payload), with the hint expected in
+    /// a transaction envelope
+    #[cfg(feature = "stellar-xdr")]
+    pub fn sign_payload_decorated(&self, payload: &[u8]) -> Result<DecoratedSignature, Error> {
+        let hint = payload_signature_hint(&self.signed_payload(payload)?);
+        Ok(DecoratedSignature {
+            hint: SignatureHint(hint),
+            signature: stellar_xdr::curr::Signature(
+                self.sign(payload)
+                    .to_bytes()
+                    .try_into()
+                    .expect("signature has 64 bytes"),
+            ),
+        })
+    }
+}
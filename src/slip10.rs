@@ -0,0 +1,62 @@
+use hmac::{digest::FixedOutput, Hmac, Mac};
+use sha2::Sha512;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+const HARDENED: u32 = 0x8000_0000;
+
+/// SLIP-10 ed25519 node: a private key and its chain code.
+///
+/// Every buffer holding key material, including the HMAC output used to
+/// derive children, is wiped when it goes out of scope.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub(crate) struct Node {
+    key: [u8; 32],
+    chain_code: [u8; 32],
+}
+
+impl Node {
+    /// Master node derived from a BIP-39 seed
+    pub fn master(seed: &[u8]) -> Self {
+        Self::from_hmac(b"ed25519 seed", &[seed])
+    }
+
+    /// Hardened child at `index`, which must not have the hardened bit set
+    pub fn derive(&self, index: u32) -> Self {
+        debug_assert!(index < HARDENED);
+        Self::from_hmac(
+            &self.chain_code,
+            &[&[0], &self.key, &(index | HARDENED).to_be_bytes()],
+        )
+    }
+
+    /// Node of an exported private key and chain code
+    pub fn from_parts(key: &[u8; 32], chain_code: &[u8; 32]) -> Self {
+        Self {
+            key: *key,
+            chain_code: *chain_code,
+        }
+    }
+
+    pub fn key(&self) -> &[u8; 32] {
+        &self.key
+    }
+
+    pub fn chain_code(&self) -> &[u8; 32] {
+        &self.chain_code
+    }
+
+    fn from_hmac(key: &[u8], data: &[&[u8]]) -> Self {
+        let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC can take key of any size");
+        for part in data {
+            mac.update(part);
+        }
+        let out = Zeroizing::new(mac.finalize_fixed());
+        let mut node = Self {
+            key: [0; 32],
+            chain_code: [0; 32],
+        };
+        node.key.copy_from_slice(&out[..32]);
+        node.chain_code.copy_from_slice(&out[32..]);
+        node
+    }
+}
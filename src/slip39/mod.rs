@@ -0,0 +1,448 @@
+//! SLIP-39 Shamir backups of a master secret.
+//!
+//! A master secret is encrypted with an optional passphrase, then split in
+//! two levels: `group_threshold` of the [`Group`]s are needed, and within
+//! each group `threshold` of its member shares. Every share is written as a
+//! mnemonic of 20 or more words.
+//!
+//! ```
+//! # use sep5::slip39::{self, Group};
+//! let secret = [7u8; 16];
+//! let groups = [Group::new(2, 3), Group::new(1, 1)];
+//! let shares = slip39::split(&secret, 1, &groups, "", 0, true)?;
+//! let recovered = slip39::combine(&shares[0][1..], "")?;
+//! assert_eq!(recovered.as_slice(), secret);
+//! # Ok::<(), sep5::Error>(())
+//! ```
+
+mod shamir;
+
+use std::collections::BTreeMap;
+
+use rand::{rngs::OsRng, RngCore};
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+use crate::error::{Error, Slip39Error};
+
+const WORDLIST: &str = include_str!("wordlist.txt");
+const RADIX_BITS: usize = 10;
+const ID_BITS: usize = 15;
+/// Identifier, flags and share parameters (4 words) plus checksum (3 words)
+const METADATA_WORDS: usize = 7;
+const CHECKSUM_WORDS: usize = 3;
+const MIN_WORDS: usize = 20;
+const MIN_SECRET_LENGTH: usize = 16;
+const MAX_SHARE_COUNT: u8 = 16;
+const BASE_ITERATION_COUNT: u32 = 10_000;
+const ROUND_COUNT: u8 = 4;
+const CUSTOMIZATION: &[u8] = b"shamir";
+const CUSTOMIZATION_EXTENDABLE: &[u8] = b"shamir_extendable";
+const GENERATOR: [u32; 10] = [
+    0xe0e040, 0x1c1c080, 0x3838100, 0x7070200, 0xe0e0009, 0x1c0c2412, 0x38086c24, 0x3090fc48,
+    0x21b1f890, 0x3f3f120,
+];
+
+/// Member shares of one group: any `threshold` of the `count` shares
+/// recover the group's part of the secret
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Group {
+    pub threshold: u8,
+    pub count: u8,
+}
+
+impl Group {
+    pub fn new(threshold: u8, count: u8) -> Self {
+        Self { threshold, count }
+    }
+}
+
+/// A decoded share mnemonic
+struct Share {
+    identifier: u16,
+    extendable: bool,
+    iteration_exponent: u8,
+    group_index: u8,
+    group_threshold: u8,
+    group_count: u8,
+    member_index: u8,
+    member_threshold: u8,
+    value: Zeroizing<Vec<u8>>,
+}
+
+/// Split `master_secret` into share mnemonics, one list per group.
+///
+/// The secret must be at least 16 bytes long and have an even length.
+/// Recovering it takes `10000 << iteration_exponent` PBKDF2 iterations, and
+/// `extendable` backups allow adding shares with the same identifier later.
+pub fn split(
+    master_secret: &[u8],
+    group_threshold: u8,
+    groups: &[Group],
+    passphrase: &str,
+    iteration_exponent: u8,
+    extendable: bool,
+) -> Result<Vec<Vec<String>>, Error> {
+    if master_secret.len() < MIN_SECRET_LENGTH || !master_secret.len().is_multiple_of(2) {
+        return Err(Slip39Error::InvalidMasterSecretLength(master_secret.len()).into());
+    }
+    if group_threshold == 0 || groups.is_empty() || group_threshold as usize > groups.len() {
+        return Err(Slip39Error::InvalidGroupThreshold.into());
+    }
+    if groups.len() > MAX_SHARE_COUNT as usize || iteration_exponent > 15 {
+        return Err(Slip39Error::InvalidGroup.into());
+    }
+    for group in groups {
+        let valid = group.threshold >= 1
+            && group.threshold <= group.count
+            && group.count <= MAX_SHARE_COUNT
+            // more than one share of a 1-of-n group would just be copies
+            && (group.threshold > 1 || group.count == 1);
+        if !valid {
+            return Err(Slip39Error::InvalidGroup.into());
+        }
+    }
+    check_passphrase(passphrase)?;
+
+    let mut rng = OsRng;
+    let identifier = (rng.next_u32() as u16) & ((1 << ID_BITS) - 1);
+    let encrypted = encrypt(
+        master_secret,
+        passphrase,
+        iteration_exponent,
+        identifier,
+        extendable,
+    );
+    let group_shares =
+        shamir::split_secret(group_threshold, groups.len() as u8, &encrypted, &mut rng);
+    Ok(group_shares
+        .iter()
+        .zip(groups)
+        .map(|((group_index, group_secret), group)| {
+            shamir::split_secret(group.threshold, group.count, group_secret, &mut rng)
+                .into_iter()
+                .map(|(member_index, value)| {
+                    Share {
+                        identifier,
+                        extendable,
+                        iteration_exponent,
+                        group_index: *group_index,
+                        group_threshold,
+                        group_count: groups.len() as u8,
+                        member_index,
+                        member_threshold: group.threshold,
+                        value,
+                    }
+                    .to_mnemonic()
+                })
+                .collect()
+        })
+        .collect())
+}
+
+/// Recover the master secret from share mnemonics.
+///
+/// Shares can be given in any order, and extra shares beyond the thresholds
+/// are ignored.
+pub fn combine<S: AsRef<str>>(
+    mnemonics: &[S],
+    passphrase: &str,
+) -> Result<Zeroizing<Vec<u8>>, Error> {
+    check_passphrase(passphrase)?;
+    let shares = mnemonics
+        .iter()
+        .map(|m| Share::from_mnemonic(m.as_ref()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let first = shares.first().ok_or(Slip39Error::NoShares)?;
+    let mut groups = BTreeMap::<u8, (u8, BTreeMap<u8, &[u8]>)>::new();
+    for share in &shares {
+        let matches = share.identifier == first.identifier
+            && share.extendable == first.extendable
+            && share.iteration_exponent == first.iteration_exponent
+            && share.group_threshold == first.group_threshold
+            && share.group_count == first.group_count
+            && share.value.len() == first.value.len();
+        if !matches {
+            return Err(Slip39Error::MismatchedShares.into());
+        }
+        let (threshold, members) = groups
+            .entry(share.group_index)
+            .or_insert((share.member_threshold, BTreeMap::new()));
+        if *threshold != share.member_threshold {
+            return Err(Slip39Error::MismatchedShares.into());
+        }
+        match members.insert(share.member_index, &share.value) {
+            Some(previous) if previous != share.value.as_slice() => {
+                return Err(Slip39Error::DuplicateMemberIndex.into());
+            }
+            _ => {}
+        }
+    }
+
+    let complete = groups
+        .iter()
+        .filter(|(_, (threshold, members))| members.len() >= *threshold as usize)
+        .take(first.group_threshold as usize)
+        .collect::<Vec<_>>();
+    if complete.len() < first.group_threshold as usize {
+        return Err(Slip39Error::InsufficientShares.into());
+    }
+    let group_secrets = complete
+        .into_iter()
+        .map(|(group_index, (threshold, members))| {
+            let members = members
+                .iter()
+                .take(*threshold as usize)
+                .map(|(index, value)| (*index, *value))
+                .collect::<Vec<_>>();
+            Ok((*group_index, shamir::recover_secret(*threshold, &members)?))
+        })
+        .collect::<Result<Vec<_>, Slip39Error>>()?;
+    let group_secrets = group_secrets
+        .iter()
+        .map(|(index, secret)| (*index, secret.as_slice()))
+        .collect::<Vec<_>>();
+    let encrypted = shamir::recover_secret(first.group_threshold, &group_secrets)?;
+    Ok(decrypt(
+        &encrypted,
+        passphrase,
+        first.iteration_exponent,
+        first.identifier,
+        first.extendable,
+    ))
+}
+
+impl Share {
+    fn to_mnemonic(&self) -> String {
+        let mut words = Vec::new();
+        let header = [
+            (self.identifier as u64, ID_BITS),
+            (self.extendable as u64, 1),
+            (self.iteration_exponent as u64, 4),
+            (self.group_index as u64, 4),
+            (self.group_threshold as u64 - 1, 4),
+            (self.group_count as u64 - 1, 4),
+            (self.member_index as u64, 4),
+            (self.member_threshold as u64 - 1, 4),
+        ];
+        let mut bits = 0u64;
+        for (value, width) in header {
+            bits = bits << width | value;
+        }
+        words.extend(
+            (0..4)
+                .rev()
+                .map(|i| (bits >> (i * RADIX_BITS)) as u16 & 0x3ff),
+        );
+
+        let value_words = (self.value.len() * 8).div_ceil(RADIX_BITS);
+        let padding = value_words * RADIX_BITS - self.value.len() * 8;
+        let mut acc = 0u32;
+        let mut acc_bits = padding;
+        for byte in self.value.iter() {
+            acc = acc << 8 | *byte as u32;
+            acc_bits += 8;
+            while acc_bits >= RADIX_BITS {
+                acc_bits -= RADIX_BITS;
+                words.push((acc >> acc_bits) as u16 & 0x3ff);
+            }
+        }
+
+        let mut values = words.clone();
+        values.extend([0; CHECKSUM_WORDS]);
+        let checksum = polymod(customization(self.extendable), &values) ^ 1;
+        words.extend(
+            (0..CHECKSUM_WORDS)
+                .rev()
+                .map(|i| (checksum >> (i * RADIX_BITS)) as u16 & 0x3ff),
+        );
+        let wordlist = WORDLIST.lines().collect::<Vec<_>>();
+        words
+            .iter()
+            .map(|w| wordlist[*w as usize])
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn from_mnemonic(mnemonic: &str) -> Result<Self, Slip39Error> {
+        let words = mnemonic
+            .split_whitespace()
+            .map(|word| {
+                let lower = word.to_lowercase();
+                WORDLIST
+                    .lines()
+                    .position(|w| w == lower)
+                    .map(|i| i as u16)
+                    .ok_or_else(|| Slip39Error::InvalidWord(word.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if words.len() < MIN_WORDS {
+            return Err(Slip39Error::InvalidLength(words.len()));
+        }
+        let padding = (RADIX_BITS * (words.len() - METADATA_WORDS)) % 16;
+        if padding > 8 {
+            return Err(Slip39Error::InvalidLength(words.len()));
+        }
+        let extendable = words[1] >> 4 & 1 == 1;
+        if polymod(customization(extendable), &words) != 1 {
+            return Err(Slip39Error::InvalidChecksum);
+        }
+
+        let header = words[..4]
+            .iter()
+            .fold(0u64, |acc, w| acc << RADIX_BITS | *w as u64);
+        let field = |shift: usize, width: usize| (header >> shift & ((1 << width) - 1)) as u8;
+        let share = Share {
+            identifier: (header >> 25) as u16,
+            extendable,
+            iteration_exponent: field(20, 4),
+            group_index: field(16, 4),
+            group_threshold: field(12, 4) + 1,
+            group_count: field(8, 4) + 1,
+            member_index: field(4, 4),
+            member_threshold: field(0, 4) + 1,
+            value: value_bytes(&words[4..words.len() - CHECKSUM_WORDS], padding)?,
+        };
+        if share.group_threshold > share.group_count {
+            return Err(Slip39Error::InvalidGroupThreshold);
+        }
+        if share.group_index >= share.group_count {
+            return Err(Slip39Error::InvalidGroupIndex);
+        }
+        if share.value.len() < MIN_SECRET_LENGTH || !share.value.len().is_multiple_of(2) {
+            return Err(Slip39Error::InvalidMasterSecretLength(share.value.len()));
+        }
+        Ok(share)
+    }
+}
+
+/// Unpack 10-bit words into bytes, checking the leading padding bits
+fn value_bytes(words: &[u16], padding: usize) -> Result<Zeroizing<Vec<u8>>, Slip39Error> {
+    let mut bytes = Zeroizing::new(Vec::with_capacity(words.len() * RADIX_BITS / 8));
+    let mut acc = 0u32;
+    let mut acc_bits = 0;
+    let mut skip = padding;
+    for word in words {
+        acc = acc << RADIX_BITS | *word as u32;
+        acc_bits += RADIX_BITS;
+        if skip > 0 {
+            if acc >> (acc_bits - skip) != 0 {
+                return Err(Slip39Error::InvalidPadding);
+            }
+            acc_bits -= skip;
+            acc &= (1 << acc_bits) - 1;
+            skip = 0;
+        }
+        while acc_bits >= 8 {
+            acc_bits -= 8;
+            bytes.push((acc >> acc_bits) as u8);
+        }
+        acc &= (1 << acc_bits) - 1;
+    }
+    Ok(bytes)
+}
+
+fn customization(extendable: bool) -> &'static [u8] {
+    if extendable {
+        CUSTOMIZATION_EXTENDABLE
+    } else {
+        CUSTOMIZATION
+    }
+}
+
+/// RS1024 checksum over the customization string and the words
+fn polymod(customization: &[u8], words: &[u16]) -> u32 {
+    let values = customization
+        .iter()
+        .map(|b| *b as u32)
+        .chain(words.iter().map(|w| *w as u32));
+    let mut checksum = 1u32;
+    for value in values {
+        let top = checksum >> 20;
+        checksum = (checksum & 0xfffff) << 10 ^ value;
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if top >> i & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum
+}
+
+/// SLIP-39 only allows printable ASCII passphrases
+fn check_passphrase(passphrase: &str) -> Result<(), Slip39Error> {
+    if passphrase.bytes().all(|b| (32..=126).contains(&b)) {
+        Ok(())
+    } else {
+        Err(Slip39Error::InvalidPassphrase)
+    }
+}
+
+fn encrypt(
+    secret: &[u8],
+    passphrase: &str,
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+) -> Zeroizing<Vec<u8>> {
+    feistel(
+        secret,
+        passphrase,
+        iteration_exponent,
+        identifier,
+        extendable,
+        0..ROUND_COUNT,
+    )
+}
+
+fn decrypt(
+    encrypted: &[u8],
+    passphrase: &str,
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+) -> Zeroizing<Vec<u8>> {
+    feistel(
+        encrypted,
+        passphrase,
+        iteration_exponent,
+        identifier,
+        extendable,
+        (0..ROUND_COUNT).rev(),
+    )
+}
+
+/// Four-round Feistel network keyed with PBKDF2-HMAC-SHA256
+fn feistel(
+    input: &[u8],
+    passphrase: &str,
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+    rounds: impl Iterator<Item = u8>,
+) -> Zeroizing<Vec<u8>> {
+    let half = input.len() / 2;
+    let mut left = Zeroizing::new(input[..half].to_vec());
+    let mut right = Zeroizing::new(input[half..].to_vec());
+    let mut salt = Vec::new();
+    if !extendable {
+        salt.extend_from_slice(CUSTOMIZATION);
+        salt.extend_from_slice(&identifier.to_be_bytes());
+    }
+    let iterations = (BASE_ITERATION_COUNT << iteration_exponent) / ROUND_COUNT as u32;
+    for round in rounds {
+        let mut password = Zeroizing::new(vec![round]);
+        password.extend_from_slice(passphrase.as_bytes());
+        let mut round_salt = Zeroizing::new(salt.clone());
+        round_salt.extend_from_slice(&right);
+        let mut key = Zeroizing::new(vec![0; half]);
+        pbkdf2::pbkdf2_hmac::<Sha256>(&password, &round_salt, iterations, &mut key);
+        for (l, k) in left.iter_mut().zip(key.iter()) {
+            *l ^= k;
+        }
+        std::mem::swap(&mut left, &mut right);
+    }
+    let mut output = right;
+    output.extend_from_slice(&left);
+    output
+}
@@ -0,0 +1,135 @@
+//! Shamir's secret sharing over GF(256), as specified by SLIP-39
+
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+use crate::error::Slip39Error;
+
+const DIGEST_LENGTH: usize = 4;
+const DIGEST_INDEX: u8 = 254;
+const SECRET_INDEX: u8 = 255;
+
+pub(super) type RawShare = (u8, Zeroizing<Vec<u8>>);
+
+/// Exponent and logarithm tables of GF(256) with the Rijndael polynomial
+/// and generator 3
+struct Tables {
+    exp: [u8; 255],
+    log: [u8; 256],
+}
+
+const TABLES: Tables = tables();
+
+const fn tables() -> Tables {
+    let mut tables = Tables {
+        exp: [0; 255],
+        log: [0; 256],
+    };
+    let mut poly: u16 = 1;
+    let mut i = 0;
+    while i < 255 {
+        tables.exp[i] = poly as u8;
+        tables.log[poly as usize] = i as u8;
+        poly = (poly << 1) ^ poly;
+        if poly & 0x100 != 0 {
+            poly ^= 0x11b;
+        }
+        i += 1;
+    }
+    tables
+}
+
+/// Value at `x` of the polynomial going through every share
+fn interpolate(shares: &[(u8, &[u8])], x: u8) -> Zeroizing<Vec<u8>> {
+    if let Some((_, value)) = shares.iter().find(|(share_x, _)| *share_x == x) {
+        return Zeroizing::new(value.to_vec());
+    }
+    let Tables { exp, log } = &TABLES;
+    let log_prod: usize = shares
+        .iter()
+        .map(|(share_x, _)| log[(share_x ^ x) as usize] as usize)
+        .sum();
+    let mut result = Zeroizing::new(vec![0; shares[0].1.len()]);
+    for (share_x, value) in shares {
+        let log_others: usize = shares
+            .iter()
+            .filter(|(other_x, _)| other_x != share_x)
+            .map(|(other_x, _)| log[(share_x ^ other_x) as usize] as usize)
+            .sum();
+        let log_basis =
+            (log_prod + 255 * shares.len() - log[(share_x ^ x) as usize] as usize - log_others)
+                % 255;
+        for (r, v) in result.iter_mut().zip(value.iter()) {
+            if *v != 0 {
+                *r ^= exp[(log[*v as usize] as usize + log_basis) % 255];
+            }
+        }
+    }
+    result
+}
+
+fn digest(random: &[u8], secret: &[u8]) -> [u8; DIGEST_LENGTH] {
+    let mut mac = Hmac::<Sha256>::new_from_slice(random).expect("HMAC can take key of any size");
+    mac.update(secret);
+    let mut digest = [0; DIGEST_LENGTH];
+    digest.copy_from_slice(&mac.finalize().into_bytes()[..DIGEST_LENGTH]);
+    digest
+}
+
+/// Split `secret` into `count` shares, any `threshold` of which recover it
+pub(super) fn split_secret(
+    threshold: u8,
+    count: u8,
+    secret: &[u8],
+    rng: &mut impl RngCore,
+) -> Vec<RawShare> {
+    if threshold == 1 {
+        return (0..count)
+            .map(|i| (i, Zeroizing::new(secret.to_vec())))
+            .collect();
+    }
+    let random_count = threshold - 2;
+    let mut shares = (0..random_count)
+        .map(|i| {
+            let mut value = Zeroizing::new(vec![0; secret.len()]);
+            rng.fill_bytes(&mut value);
+            (i, value)
+        })
+        .collect::<Vec<_>>();
+    let mut digest_share = Zeroizing::new(vec![0; secret.len()]);
+    rng.fill_bytes(&mut digest_share[DIGEST_LENGTH..]);
+    let digest = digest(&digest_share[DIGEST_LENGTH..], secret);
+    digest_share[..DIGEST_LENGTH].copy_from_slice(&digest);
+
+    let base = shares
+        .iter()
+        .map(|(x, value)| (*x, value.as_slice()))
+        .chain([
+            (DIGEST_INDEX, digest_share.as_slice()),
+            (SECRET_INDEX, secret),
+        ])
+        .collect::<Vec<_>>();
+    let derived = (random_count..count)
+        .map(|i| (i, interpolate(&base, i)))
+        .collect::<Vec<_>>();
+    shares.extend(derived);
+    shares
+}
+
+/// Recover the secret from exactly `threshold` shares, checking its digest
+pub(super) fn recover_secret(
+    threshold: u8,
+    shares: &[(u8, &[u8])],
+) -> Result<Zeroizing<Vec<u8>>, Slip39Error> {
+    if threshold == 1 {
+        return Ok(Zeroizing::new(shares[0].1.to_vec()));
+    }
+    let secret = interpolate(shares, SECRET_INDEX);
+    let digest_share = interpolate(shares, DIGEST_INDEX);
+    if digest_share[..DIGEST_LENGTH] != digest(&digest_share[DIGEST_LENGTH..], &secret) {
+        return Err(Slip39Error::InvalidDigest);
+    }
+    Ok(secret)
+}
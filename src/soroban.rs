@@ -0,0 +1,99 @@
+//! Signing of Soroban authorization entries, with the `stellar-xdr` feature
+
+use sha2::{Digest, Sha256};
+use stellar_xdr::curr::{
+    AccountId, Hash, HashIdPreimage, HashIdPreimageSorobanAuthorization, Limits, ReadXdr,
+    ScAddress, ScBytes, ScMap, ScMapEntry, ScSymbol, ScVal, ScVec, SorobanAuthorizationEntry,
+    SorobanAuthorizedInvocation, SorobanCredentials, Uint256, WriteXdr,
+};
+
+use crate::{error::Error, key_pair::KeyPair};
+
+/// Hash signed to authorize `invocation` with the credentials `nonce`, until
+/// `signature_expiration_ledger` on the network with `network_passphrase`
+pub fn authorization_hash(
+    invocation: &SorobanAuthorizedInvocation,
+    nonce: i64,
+    signature_expiration_ledger: u32,
+    network_passphrase: &str,
+) -> Result<[u8; 32], Error> {
+    let preimage = HashIdPreimage::SorobanAuthorization(HashIdPreimageSorobanAuthorization {
+        network_id: Hash(Sha256::digest(network_passphrase).into()),
+        nonce,
+        signature_expiration_ledger,
+        invocation: invocation.clone(),
+    });
+    Ok(Sha256::digest(preimage.to_xdr(Limits::none())?).into())
+}
+
+impl KeyPair {
+    /// Sign `entry` for the account of this key pair, valid until
+    /// `signature_expiration_ledger`.
+    ///
+    /// The signature is stored in the address credentials as the
+    /// `[{public_key, signature}]` value expected by Stellar accounts.
+    /// Entries using the transaction source account credentials are left
+    /// untouched as the transaction signature already authorizes them.
+    pub fn sign_authorization_entry(
+        &self,
+        entry: &mut SorobanAuthorizationEntry,
+        network_passphrase: &str,
+        signature_expiration_ledger: u32,
+    ) -> Result<(), Error> {
+        let SorobanCredentials::Address(credentials) = &mut entry.credentials else {
+            return Ok(());
+        };
+        let public_key = self.public().0;
+        let ScAddress::Account(AccountId(stellar_xdr::curr::PublicKey::PublicKeyTypeEd25519(
+            Uint256(address),
+        ))) = credentials.address
+        else {
+            return Err(Error::WrongAuthorizationAddress);
+        };
+        if address != public_key {
+            return Err(Error::WrongAuthorizationAddress);
+        }
+        let hash = authorization_hash(
+            &entry.root_invocation,
+            credentials.nonce,
+            signature_expiration_ledger,
+            network_passphrase,
+        )?;
+        let signature = self.sign(&hash);
+        let entry = ScMap(
+            vec![
+                ScMapEntry {
+                    key: ScVal::Symbol(ScSymbol("public_key".try_into()?)),
+                    val: ScVal::Bytes(ScBytes(public_key.try_into()?)),
+                },
+                ScMapEntry {
+                    key: ScVal::Symbol(ScSymbol("signature".try_into()?)),
+                    val: ScVal::Bytes(ScBytes(signature.to_bytes().try_into()?)),
+                },
+            ]
+            .try_into()?,
+        );
+        credentials.signature_expiration_ledger = signature_expiration_ledger;
+        credentials.signature = ScVal::Vec(Some(ScVec(vec![ScVal::Map(Some(entry))].try_into()?)));
+        Ok(())
+    }
+
+    /// Like [`sign_authorization_entry`](Self::sign_authorization_entry), for
+    /// an entry encoded as base64 XDR
+    pub fn sign_authorization_entry_xdr(
+        &self,
+        entry: &str,
+        network_passphrase: &str,
+        signature_expiration_ledger: u32,
+    ) -> Result<String, Error> {
+        let mut entry = SorobanAuthorizationEntry::from_xdr_base64(
+            entry,
+            Limits {
+                depth: 500,
+                len: entry.len(),
+            },
+        )?;
+        self.sign_authorization_entry(&mut entry, network_passphrase, signature_expiration_ledger)?;
+        Ok(entry.to_xdr_base64(Limits::none())?)
+    }
+}
@@ -0,0 +1,113 @@
+//! Signing of Stellar transaction envelopes, with the `stellar-xdr` feature
+
+use sha2::{Digest, Sha256};
+use stellar_xdr::curr::{
+    DecoratedSignature, Hash, Limits, MuxedAccount, Preconditions, ReadXdr, SignatureHint,
+    Transaction, TransactionEnvelope, TransactionExt, TransactionSignaturePayload,
+    TransactionSignaturePayloadTaggedTransaction, TransactionV0, VecM, WriteXdr,
+};
+
+use crate::{error::Error, key_pair::KeyPair};
+
+/// Network passphrase of the public Stellar network
+pub const PUBLIC_NETWORK: &str = "Public Global Stellar Network ; September 2015";
+/// Network passphrase of the Stellar test network
+pub const TEST_NETWORK: &str = "Test SDF Network ; September 2015";
+
+/// Hash signed by the signers of `envelope` on the network with
+/// `network_passphrase`.
+///
+/// V0 envelopes are hashed as the equivalent V1 transaction, like
+/// stellar-core does.
+pub fn hash(envelope: &TransactionEnvelope, network_passphrase: &str) -> Result<[u8; 32], Error> {
+    let tagged_transaction = match envelope {
+        TransactionEnvelope::TxV0(envelope) => {
+            TransactionSignaturePayloadTaggedTransaction::Tx(v1_transaction(&envelope.tx))
+        }
+        TransactionEnvelope::Tx(envelope) => {
+            TransactionSignaturePayloadTaggedTransaction::Tx(envelope.tx.clone())
+        }
+        TransactionEnvelope::TxFeeBump(envelope) => {
+            TransactionSignaturePayloadTaggedTransaction::TxFeeBump(envelope.tx.clone())
+        }
+    };
+    let payload = TransactionSignaturePayload {
+        network_id: Hash(Sha256::digest(network_passphrase).into()),
+        tagged_transaction,
+    };
+    Ok(Sha256::digest(payload.to_xdr(Limits::none())?).into())
+}
+
+fn v1_transaction(tx: &TransactionV0) -> Transaction {
+    Transaction {
+        source_account: MuxedAccount::Ed25519(tx.source_account_ed25519.clone()),
+        fee: tx.fee,
+        seq_num: tx.seq_num.clone(),
+        cond: tx
+            .time_bounds
+            .clone()
+            .map_or(Preconditions::None, Preconditions::Time),
+        memo: tx.memo.clone(),
+        operations: tx.operations.clone(),
+        ext: TransactionExt::V0,
+    }
+}
+
+impl KeyPair {
+    /// Last 4 bytes of the public key, sent with signatures to tell signers
+    /// apart
+    pub fn signature_hint(&self) -> SignatureHint {
+        let public_key = self.public().0;
+        SignatureHint(public_key[28..].try_into().expect("slice has 4 bytes"))
+    }
+
+    /// Sign a 32-byte transaction hash, see [`hash`]
+    pub fn sign_decorated(&self, hash: &[u8; 32]) -> DecoratedSignature {
+        DecoratedSignature {
+            hint: self.signature_hint(),
+            signature: stellar_xdr::curr::Signature(
+                self.sign(hash)
+                    .to_bytes()
+                    .try_into()
+                    .expect("signature has 64 bytes"),
+            ),
+        }
+    }
+
+    /// Sign `envelope` for the network with `network_passphrase`, appending
+    /// the signature to the envelope's signatures
+    pub fn sign_transaction(
+        &self,
+        envelope: &mut TransactionEnvelope,
+        network_passphrase: &str,
+    ) -> Result<(), Error> {
+        let signature = self.sign_decorated(&hash(envelope, network_passphrase)?);
+        let signatures = match envelope {
+            TransactionEnvelope::TxV0(envelope) => &mut envelope.signatures,
+            TransactionEnvelope::Tx(envelope) => &mut envelope.signatures,
+            TransactionEnvelope::TxFeeBump(envelope) => &mut envelope.signatures,
+        };
+        let mut appended = signatures.to_vec();
+        appended.push(signature);
+        *signatures = VecM::try_from(appended).map_err(|_| Error::TooManySignatures)?;
+        Ok(())
+    }
+
+    /// Like [`sign_transaction`](Self::sign_transaction), for an envelope
+    /// encoded as base64 XDR
+    pub fn sign_transaction_xdr(
+        &self,
+        envelope: &str,
+        network_passphrase: &str,
+    ) -> Result<String, Error> {
+        let mut envelope = TransactionEnvelope::from_xdr_base64(
+            envelope,
+            Limits {
+                depth: 500,
+                len: envelope.len(),
+            },
+        )?;
+        self.sign_transaction(&mut envelope, network_passphrase)?;
+        Ok(envelope.to_xdr_base64(Limits::none())?)
+    }
+}
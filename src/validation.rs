@@ -0,0 +1,157 @@
+use std::fmt::Display;
+
+use bip39::{Mnemonic, MnemonicType};
+use unicode_normalization::UnicodeNormalization;
+
+use crate::language::{self, Language};
+
+/// Maximum number of suggestions reported for an unknown word
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Every problem found in a mnemonic, see [`validate`].
+///
+/// Display and Debug only show counts and positions, so that logging a
+/// report does not leak words of the phrase.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidationReport {
+    /// Wordlist the phrase was checked against
+    pub language: Language,
+    pub word_count: usize,
+    /// Whether the phrase has 12, 15, 18, 21 or 24 words
+    pub word_count_valid: bool,
+    /// Words missing from the wordlist, in phrase order
+    pub unknown_words: Vec<UnknownWord>,
+    /// `None` when the checksum could not be computed because of an invalid
+    /// word count or unknown words
+    pub checksum_valid: Option<bool>,
+}
+
+/// A word that is not in the wordlist
+#[derive(Clone, PartialEq, Eq)]
+pub struct UnknownWord {
+    /// Zero-based index of the word in the phrase
+    pub position: usize,
+    pub word: String,
+    /// Closest wordlist entries, best first: words sharing the first four
+    /// letters, then words within an edit distance of two (less for short
+    /// words)
+    pub suggestions: Vec<String>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.word_count_valid && self.unknown_words.is_empty() && self.checksum_valid == Some(true)
+    }
+}
+
+impl Display for ValidationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut problems = Vec::new();
+        if !self.word_count_valid {
+            problems.push(format!(
+                "expected 12, 15, 18, 21 or 24 words, found {}",
+                self.word_count
+            ));
+        }
+        for unknown in &self.unknown_words {
+            // positions are counted from one for people reading the phrase
+            let mut problem = format!("unknown word at position {}", unknown.position + 1);
+            if !unknown.suggestions.is_empty() {
+                problem.push_str(&format!(" ({} suggestions)", unknown.suggestions.len()));
+            }
+            problems.push(problem);
+        }
+        if self.checksum_valid == Some(false) {
+            problems.push("invalid checksum".to_string());
+        }
+        if problems.is_empty() {
+            f.write_str("valid")
+        } else {
+            f.write_str(&problems.join("; "))
+        }
+    }
+}
+
+impl std::fmt::Debug for UnknownWord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnknownWord")
+            .field("position", &self.position)
+            .field("word", &"[REDACTED]")
+            .field("suggestions", &self.suggestions.len())
+            .finish()
+    }
+}
+
+/// Check a phrase against its detected wordlist, see [`language::detect`]
+pub fn validate(phrase: &str) -> ValidationReport {
+    validate_in(phrase, language::detect(phrase))
+}
+
+/// Check a phrase against the `language` wordlist
+pub fn validate_in(phrase: &str, language: Language) -> ValidationReport {
+    let words = language::normalized_words(phrase);
+    let wordmap = language.wordmap();
+    let unknown_words = words
+        .iter()
+        .enumerate()
+        .filter(|(_, word)| wordmap.get_bits(word).is_none())
+        .map(|(position, word)| UnknownWord {
+            position,
+            word: word.nfc().collect(),
+            suggestions: suggestions(word, language),
+        })
+        .collect::<Vec<_>>();
+    let word_count_valid = MnemonicType::for_word_count(words.len()).is_ok();
+    let checksum_valid = (word_count_valid && unknown_words.is_empty())
+        .then(|| Mnemonic::validate(&words.join(" "), language).is_ok());
+    ValidationReport {
+        language,
+        word_count: words.len(),
+        word_count_valid,
+        unknown_words,
+        checksum_valid,
+    }
+}
+
+/// Wordlist entries close to `word`, which must be NFKD normalized
+fn suggestions(word: &str, language: Language) -> Vec<String> {
+    let word = word.chars().collect::<Vec<_>>();
+    let prefix = &word[..word.len().min(4)];
+    // a single edit already turns a one-character word into any other
+    let max_distance = (word.len() / 2).min(2);
+    let wordlist = language.wordlist();
+    let mut candidates = (0..2048u16)
+        .filter_map(|i| {
+            let candidate = wordlist.get_word(i.into());
+            let candidate_chars = candidate.chars().collect::<Vec<_>>();
+            let prefix_match = candidate_chars.starts_with(prefix);
+            let distance = edit_distance(&word, &candidate_chars);
+            (prefix_match || distance <= max_distance).then_some((
+                !prefix_match,
+                distance,
+                i,
+                candidate,
+            ))
+        })
+        .collect::<Vec<_>>();
+    candidates.sort_unstable();
+    candidates
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, _, _, candidate)| candidate.nfc().collect())
+        .collect()
+}
+
+/// Levenshtein distance between two words
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.iter().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
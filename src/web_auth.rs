@@ -0,0 +1,211 @@
+//! SEP-10 web authentication, with the `stellar-xdr` feature.
+//!
+//! A client proves it controls an account by co-signing a challenge
+//! transaction built by the server. The challenge is never submitted to the
+//! network, but a malicious server could send a real transaction instead, so
+//! it is checked thoroughly before signing.
+//!
+//! ```no_run
+//! # use sep5::{web_auth::WebAuth, SeedPhrase, transaction::TEST_NETWORK};
+//! # use stellar_strkey::ed25519::PublicKey;
+//! # let challenge = "";
+//! let key = SeedPhrase::from_seed_phrase("...")?.from_path_index(0, None)?;
+//! let server_account =
+//!     PublicKey::from_string("GDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUJUJ6")
+//!         .map_err(|_| sep5::Error::InvalidPublicKey)?;
+//! let web_auth = WebAuth {
+//!     server_account,
+//!     home_domain: "example.com",
+//!     web_auth_domain: "auth.example.com",
+//!     network_passphrase: TEST_NETWORK,
+//!     client_domain: None,
+//! };
+//! let signed = web_auth.sign_challenge(challenge, &key)?;
+//! # Ok::<(), sep5::Error>(())
+//! ```
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use stellar_strkey::ed25519::PublicKey;
+use stellar_xdr::curr::{
+    Limits, ManageDataOp, Memo, MuxedAccount, Operation, OperationBody, Preconditions, ReadXdr,
+    TimeBounds, TransactionEnvelope, WriteXdr,
+};
+
+use crate::{
+    error::{ChallengeError, Error},
+    key_pair::{self, KeyPair, Signature},
+    transaction,
+};
+
+/// Tolerated clock difference with the server, in seconds
+pub const GRACE_PERIOD: u64 = 5 * 60;
+
+/// Length of the base64 encoded random nonce of a challenge
+const NONCE_LENGTH: usize = 64;
+
+/// The SEP-10 server a challenge must come from
+#[derive(Clone, Debug)]
+pub struct WebAuth<'a> {
+    /// `SIGNING_KEY` from the server's stellar.toml
+    pub server_account: PublicKey,
+    pub home_domain: &'a str,
+    /// Domain of the authentication endpoint
+    pub web_auth_domain: &'a str,
+    pub network_passphrase: &'a str,
+    /// Client domain requested with the challenge, if any
+    pub client_domain: Option<ClientDomain<'a>>,
+}
+
+/// A client domain attesting the client, as in SEP-10 client attribution
+#[derive(Clone, Debug)]
+pub struct ClientDomain<'a> {
+    pub domain: &'a str,
+    /// `SIGNING_KEY` from the client domain's stellar.toml
+    pub signing_key: PublicKey,
+}
+
+impl WebAuth<'_> {
+    /// Validate a base64 XDR challenge and co-sign it with `key`
+    pub fn sign_challenge(&self, challenge: &str, key: &KeyPair) -> Result<String, Error> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        self.sign_challenge_at(challenge, key, now)
+    }
+
+    /// Like [`sign_challenge`](Self::sign_challenge), at `now` seconds since
+    /// the Unix epoch
+    pub fn sign_challenge_at(
+        &self,
+        challenge: &str,
+        key: &KeyPair,
+        now: u64,
+    ) -> Result<String, Error> {
+        let mut envelope = TransactionEnvelope::from_xdr_base64(
+            challenge,
+            Limits {
+                depth: 500,
+                len: challenge.len(),
+            },
+        )?;
+        self.validate_challenge(&envelope, &key.public(), now)?;
+        key.sign_transaction(&mut envelope, self.network_passphrase)?;
+        Ok(envelope.to_xdr_base64(Limits::none())?)
+    }
+
+    /// Check that `envelope` is a challenge from this server for `client`,
+    /// valid at `now` seconds since the Unix epoch
+    pub fn validate_challenge(
+        &self,
+        envelope: &TransactionEnvelope,
+        client: &PublicKey,
+        now: u64,
+    ) -> Result<(), Error> {
+        let TransactionEnvelope::Tx(envelope) = envelope else {
+            return Err(ChallengeError::NotV1Envelope.into());
+        };
+        let tx = &envelope.tx;
+        if !is_account(&tx.source_account, &self.server_account) {
+            return Err(ChallengeError::WrongSourceAccount.into());
+        }
+        if tx.seq_num.0 != 0 {
+            return Err(ChallengeError::NonZeroSequence.into());
+        }
+        if !matches!(tx.memo, Memo::None | Memo::Id(_)) {
+            return Err(ChallengeError::InvalidMemo.into());
+        }
+        let time_bounds = match &tx.cond {
+            Preconditions::Time(time_bounds) => Some(time_bounds),
+            Preconditions::V2(preconditions) => preconditions.time_bounds.as_ref(),
+            Preconditions::None => None,
+        };
+        let Some(TimeBounds { min_time, max_time }) = time_bounds else {
+            return Err(ChallengeError::MissingTimeBounds.into());
+        };
+        if max_time.0 == 0 || now > max_time.0 || now.saturating_add(GRACE_PERIOD) < min_time.0 {
+            return Err(ChallengeError::Expired.into());
+        }
+
+        let (first, rest) = tx
+            .operations
+            .split_first()
+            .ok_or(ChallengeError::NoOperations)?;
+        let (source, data) = manage_data(first)?;
+        if source.map(account_id) != Some(client.0) {
+            return Err(ChallengeError::WrongClientAccount.into());
+        }
+        if data.data_name.0.as_slice() != format!("{} auth", self.home_domain).as_bytes() {
+            return Err(ChallengeError::WrongHomeDomain.into());
+        }
+        if data.data_value.as_ref().map(|v| v.0.len()) != Some(NONCE_LENGTH) {
+            return Err(ChallengeError::InvalidNonce.into());
+        }
+        let mut has_client_domain = false;
+        for operation in rest {
+            let (source, data) = manage_data(operation)?;
+            let name = data.data_name.0.as_slice();
+            // the client domain operation is signed by the client domain account
+            if name == b"client_domain" {
+                let client_domain = self
+                    .client_domain
+                    .as_ref()
+                    .ok_or(ChallengeError::UnexpectedClientDomain)?;
+                if !source.is_some_and(|source| is_account(source, &client_domain.signing_key))
+                    || data.data_value.as_ref().map(|v| v.0.as_slice())
+                        != Some(client_domain.domain.as_bytes())
+                {
+                    return Err(ChallengeError::WrongClientDomain.into());
+                }
+                has_client_domain = true;
+                continue;
+            }
+            if !source.is_some_and(|source| is_account(source, &self.server_account)) {
+                return Err(ChallengeError::UnexpectedOperationSource.into());
+            }
+            if name == b"web_auth_domain"
+                && data.data_value.as_ref().map(|v| v.0.as_slice())
+                    != Some(self.web_auth_domain.as_bytes())
+            {
+                return Err(ChallengeError::WrongWebAuthDomain.into());
+            }
+        }
+        if self.client_domain.is_some() && !has_client_domain {
+            return Err(ChallengeError::MissingClientDomain.into());
+        }
+
+        let hash = transaction::hash(
+            &TransactionEnvelope::Tx(envelope.clone()),
+            self.network_passphrase,
+        )?;
+        let signed_by_server = envelope.signatures.iter().any(|signature| {
+            Signature::from_slice(&signature.signature.0).is_ok_and(|signature| {
+                key_pair::verify(&self.server_account, &hash, &signature).is_ok()
+            })
+        });
+        if envelope.signatures.len() != 1 || !signed_by_server {
+            return Err(ChallengeError::MissingServerSignature.into());
+        }
+        Ok(())
+    }
+}
+
+/// Whether `account` is `public_key`, and not a muxed account of it
+fn is_account(account: &MuxedAccount, public_key: &PublicKey) -> bool {
+    matches!(account, MuxedAccount::Ed25519(key) if key.0 == public_key.0)
+}
+
+fn account_id(account: &MuxedAccount) -> [u8; 32] {
+    match account {
+        MuxedAccount::Ed25519(key) => key.0,
+        MuxedAccount::MuxedEd25519(muxed) => muxed.ed25519.0,
+    }
+}
+
+/// Source account and body of a manage data operation
+fn manage_data(operation: &Operation) -> Result<(Option<&MuxedAccount>, &ManageDataOp), Error> {
+    let OperationBody::ManageData(data) = &operation.body else {
+        return Err(ChallengeError::NotManageData.into());
+    };
+    Ok((operation.source_account.as_ref(), data))
+}
@@ -0,0 +1,97 @@
+mod common;
+
+use common::TWELVE;
+use sep5::{Error, Language, MnemonicType, SeedPhrase};
+
+#[test]
+fn mnemonics() {
+    let phrase: SeedPhrase = TWELVE.parse().unwrap();
+    for (word_count, index, passphrase, expected) in [
+        (
+            MnemonicType::Words12,
+            0,
+            None,
+            "east blossom obtain feature diary fire bright light gallery vapor ski grow",
+        ),
+        (
+            MnemonicType::Words24,
+            1,
+            None,
+            "steel hint banana master attend trim maximum razor fog cactus club burden response amateur ribbon lazy muffin month effort snap bleak coast base false",
+        ),
+        (
+            MnemonicType::Words12,
+            0,
+            Some("TREZOR"),
+            "agree prize tip merry frog nasty tobacco dice cargo dwarf rare blouse",
+        ),
+    ] {
+        let child = phrase
+            .bip85_mnemonic(Language::English, word_count, index, passphrase)
+            .unwrap();
+        assert_eq!(child.phrase(), expected);
+    }
+
+    // the child can be regenerated and derives its own SEP-5 keys
+    let child = phrase
+        .bip85_mnemonic(Language::English, MnemonicType::Words12, 0, None)
+        .unwrap();
+    let again = phrase
+        .bip85_mnemonic(Language::English, MnemonicType::Words12, 0, None)
+        .unwrap();
+    assert_eq!(
+        child.empty_key(None).unwrap().public(),
+        again.empty_key(None).unwrap().public()
+    );
+    assert_ne!(
+        child.empty_key(None).unwrap().public(),
+        phrase.empty_key(None).unwrap().public()
+    );
+
+    for (word_count, words) in [(MnemonicType::Words15, 15), (MnemonicType::Words21, 21)] {
+        assert!(matches!(
+            phrase.bip85_mnemonic(Language::English, word_count, 0, None),
+            Err(Error::UnsupportedWordCount(n)) if n == words
+        ));
+    }
+}
+
+#[test]
+fn japanese_mnemonic() {
+    let phrase: SeedPhrase = TWELVE.parse().unwrap();
+    let child = phrase
+        .bip85_mnemonic(Language::Japanese, MnemonicType::Words18, 2, None)
+        .unwrap();
+    let entropy = [
+        0x0b, 0x80, 0x07, 0x1e, 0xfd, 0xf9, 0x84, 0x03, 0x71, 0xa7, 0xd5, 0x55, 0x95, 0xc7, 0xae,
+        0x79, 0xcb, 0x40, 0x67, 0xd7, 0xa5, 0x94, 0x1b, 0xd0,
+    ];
+    let expected = SeedPhrase::from_entropy_in(&entropy, Language::Japanese).unwrap();
+    assert_eq!(child.phrase(), expected.phrase());
+}
+
+#[test]
+fn hex_and_password() {
+    let phrase: SeedPhrase = TWELVE.parse().unwrap();
+    assert_eq!(
+        phrase.bip85_hex(32, 0, None).unwrap().as_str(),
+        "5588aa7b4b216a7725b6191e2036e8bf67f93c557e0ef46ab9741afa38ac2e2b"
+    );
+    assert_eq!(
+        phrase.bip85_password(20, 3, None).unwrap().as_str(),
+        "Ll/FN48rb8RxZxsUJ9td"
+    );
+
+    assert!(matches!(
+        phrase.bip85_hex(15, 0, None),
+        Err(Error::InvalidLength {
+            length: 15,
+            min: 16,
+            max: 64
+        })
+    ));
+    assert!(matches!(
+        phrase.bip85_password(87, 0, None),
+        Err(Error::InvalidLength { length: 87, .. })
+    ));
+}
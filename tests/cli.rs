@@ -0,0 +1,116 @@
+#![cfg(feature = "cli")]
+
+mod common;
+
+use common::TWELVE;
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+fn sep5(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_sep5"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn inspect() {
+    let output = sep5(&["inspect", "--json"], TWELVE);
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        json["public_key"],
+        "GA7YP3KZXA3FSDPOJCMNBABV3OJYAPUOWNTXN7TP6BP4HYQHDYNKW5FB"
+    );
+    assert!(json.get("seed").is_none());
+    assert!(json.get("secret_key").is_none());
+    let output = sep5(&["inspect"], TWELVE);
+    assert!(!String::from_utf8(output.stdout).unwrap().contains("secret"));
+
+    let output = sep5(&["inspect", "--json", "--show-secret"], TWELVE);
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["seed"], "e4a5a632e70943ae7f07659df1332160937fad82587216a4c64315a0fb39497ee4a01f76ddab4cba68147977f3a147b6ad584c41808e8238a07f6cc4b582f186");
+    assert_eq!(
+        json["public_key"],
+        "GA7YP3KZXA3FSDPOJCMNBABV3OJYAPUOWNTXN7TP6BP4HYQHDYNKW5FB"
+    );
+    assert_eq!(
+        json["secret_key"],
+        "SDQO5SCP4FS42QT4W66JW3H554CVLKQ4W34QIP7R72MGYPEN3UROHSIG"
+    );
+}
+
+#[test]
+fn derive_range() {
+    let output = sep5(&["derive", "--range", "1..3"], TWELVE);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "1 m/44'/148'/1' GBAW5XGWORWVFE2XTJYDTLDHXTY2Q2MO73HYCGB3XMFMQ562Q2W2GJQX\n\
+         2 m/44'/148'/2' GAY5PRAHJ2HIYBYCLZXTHID6SPVELOOYH2LBPH3LD4RUMXUW3DOYTLXW\n"
+    );
+}
+
+#[test]
+fn derive_index() {
+    let output = sep5(
+        &["derive", "--index", "2", "--show-secret", "--json"],
+        TWELVE,
+    );
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        json[0]["public_key"],
+        "GAY5PRAHJ2HIYBYCLZXTHID6SPVELOOYH2LBPH3LD4RUMXUW3DOYTLXW"
+    );
+    assert!(json[0]["secret_key"].as_str().unwrap().starts_with('S'));
+
+    let output = sep5(&["derive", "--index", &usize::MAX.to_string()], TWELVE);
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "error: account index is too large\n"
+    );
+}
+
+#[test]
+fn verify() {
+    let output = sep5(
+        &[
+            "verify",
+            "--public-key",
+            "GAY5PRAHJ2HIYBYCLZXTHID6SPVELOOYH2LBPH3LD4RUMXUW3DOYTLXW",
+            "--json",
+        ],
+        TWELVE,
+    );
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["index"], 2);
+
+    let output = sep5(&["verify"], "illness spike retreat");
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn generate() {
+    let output = sep5(&["generate", "--words", "12", "--language", "spanish"], "");
+    assert!(output.status.success());
+    let phrase = String::from_utf8(output.stdout).unwrap();
+    let seed_phrase = sep5::SeedPhrase::from_seed_phrase(phrase.trim()).unwrap();
+    assert_eq!(seed_phrase.language(), sep5::Language::Spanish);
+    assert_eq!(phrase.split_whitespace().count(), 12);
+}
@@ -0,0 +1,115 @@
+mod common;
+
+use common::{ABANDON, TWELVE};
+use sep5::{CoinProfile, Error, SeedPhrase};
+
+fn test_coin(phrase: &str, coin: CoinProfile, addresses: &[&str]) {
+    let phrase = SeedPhrase::from_seed_phrase(phrase).unwrap();
+    for (n, address) in addresses.iter().enumerate() {
+        let key_pair = phrase.coin_account(&coin, n, None).unwrap();
+        assert_eq!(
+            &key_pair.address(&coin),
+            address,
+            "{} account {n}",
+            coin.name
+        );
+    }
+}
+
+#[test]
+fn stellar() {
+    test_coin(
+        TWELVE,
+        CoinProfile::STELLAR,
+        &[
+            "GDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUJUJ6",
+            "GBAW5XGWORWVFE2XTJYDTLDHXTY2Q2MO73HYCGB3XMFMQ562Q2W2GJQX",
+        ],
+    );
+}
+
+#[test]
+fn solana() {
+    test_coin(
+        ABANDON,
+        CoinProfile::SOLANA,
+        &[
+            "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk",
+            "Hh8QwFUA6MtVu1qAoq12ucvFHNwCcVTV7hpWjeY1Hztb",
+        ],
+    );
+    test_coin(
+        TWELVE,
+        CoinProfile::SOLANA,
+        &[
+            "EfMyugvKBr7JPsxFTUE7Z67sBNJj9NWCRbyt9p7fojBN",
+            "HA7ixLo74uDdwGNKEY1amBoqwFtmmJcYGWBk4ZfRpcVU",
+        ],
+    );
+}
+
+#[test]
+fn aptos() {
+    test_coin(
+        ABANDON,
+        CoinProfile::APTOS,
+        &[
+            "0xeb663b681209e7087d681c5d3eed12aaa8e1915e7c87794542c3f96e94b3d3bf",
+            "0xf867372dfec13fb6c0740d4b574363685e10e6f243e9554ffa8f6e698e940efa",
+        ],
+    );
+}
+
+#[test]
+fn sui() {
+    test_coin(
+        ABANDON,
+        CoinProfile::SUI,
+        &[
+            "0x5e93a736d04fbb25737aa40bee40171ef79f65fae833749e3c089fe7cc2161f1",
+            "0x082d099250999ab8450a9ef3a962edf9e2449e1045be32ba5a0f2c6117ff7167",
+        ],
+    );
+}
+
+#[test]
+fn near() {
+    test_coin(
+        ABANDON,
+        CoinProfile::NEAR,
+        &[
+            "5510e2b44cae6eb807e3e0e45d579dda058c274abcba15e5cb84636f5d1ee412",
+            "3b93b03253b9715213ec314eb50ecc99d25602ccb5b059f91f51d24710d54326",
+        ],
+    );
+}
+
+#[test]
+fn coin_paths() {
+    assert_eq!(
+        CoinProfile::SOLANA.account_path(3).unwrap().to_string(),
+        "m/44'/501'/3'/0'"
+    );
+    assert_eq!(
+        CoinProfile::SUI.account_path(0).unwrap().to_string(),
+        "m/44'/784'/0'/0'/0'"
+    );
+    assert!(matches!(
+        CoinProfile::NEAR.account_path(1 << 31),
+        Err(Error::InvalidIndex { path }) if path == "m/44'/397'/2147483648'"
+    ));
+    assert_eq!(CoinProfile::from_name("Solana"), Some(CoinProfile::SOLANA));
+    assert_eq!(CoinProfile::from_name("algorand"), None);
+
+    let phrase = SeedPhrase::from_seed_phrase(ABANDON).unwrap();
+    assert_eq!(
+        phrase
+            .from_coin_path_string(&CoinProfile::SOLANA, "/0'/0'", None)
+            .unwrap()
+            .public(),
+        phrase
+            .coin_account(&CoinProfile::SOLANA, 0, None)
+            .unwrap()
+            .public()
+    );
+}
@@ -0,0 +1,21 @@
+//! Fixtures shared by the integration tests
+
+#![allow(dead_code)]
+
+use sep5::{KeyPair, SeedPhrase};
+
+/// The 12 word phrase of the SEP-5 test vectors
+pub const TWELVE: &str = "illness spike retreat truth genius clock brain pass fit cave bargain toe";
+
+/// The 12 word phrase of all-zero entropy, used by the vectors of other
+/// chains
+pub const ABANDON: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+/// Key of the account `index` of [`TWELVE`], without passphrase
+pub fn account(index: usize) -> KeyPair {
+    SeedPhrase::from_seed_phrase(TWELVE)
+        .unwrap()
+        .from_path_index(index, None)
+        .unwrap()
+}
@@ -0,0 +1,120 @@
+mod common;
+
+use common::{ABANDON, TWELVE};
+use sep5::{
+    error::SegmentError, Bip32Path, Curve, CurveKey, DerivationPath, Error, PathError,
+    Secp256k1KeyPair, SeedPhrase,
+};
+
+#[test]
+fn secp256k1_keys() {
+    let path: Bip32Path = "m/44'/60'/0'/0/0".parse().unwrap();
+    for (phrase, private_key, public_key, uncompressed) in [
+        (
+            ABANDON,
+            "1ab42cc412b618bdea3a599e3c9bae199ebf030895b039e9db1e30dafb12b727",
+            "0237b0bb7a8288d38ed49a524b5dc98cff3eb5ca824c9f9dc0dfdb3d9cd600f299",
+            "0437b0bb7a8288d38ed49a524b5dc98cff3eb5ca824c9f9dc0dfdb3d9cd600f299a6179912b7451c09896c4098eca7ce6b2e58330672795e847c4d6af44e024230",
+        ),
+        (
+            TWELVE,
+            "a372be85c90ff55eb343815d88ecc36938e0b84641a66faeb0d0627e5caedc9a",
+            "02866b53a8382c7f056e12b70484486a86c7ba7840debf8e403a1018b34c29cdea",
+            "04866b53a8382c7f056e12b70484486a86c7ba7840debf8e403a1018b34c29cdea258a8b3fa31bd5573d84211cffc22da1c6e7fbeaa7eb90675abcd9927bed8bf8",
+        ),
+    ] {
+        let phrase = SeedPhrase::from_seed_phrase(phrase)
+            .unwrap()
+            .with_curve(Curve::Secp256k1);
+        let key = phrase.derive_key(&path, None).unwrap();
+        assert_eq!(key.curve(), Curve::Secp256k1);
+        assert_eq!(hex::encode(&key.private_key()[..]), private_key);
+        assert_eq!(hex::encode(key.public_key()), public_key);
+        let key = key.as_secp256k1().unwrap();
+        assert_eq!(hex::encode(key.uncompressed_public_key()), uncompressed);
+    }
+}
+
+#[test]
+fn one_backup_both_curves() {
+    let phrase = SeedPhrase::from_seed_phrase(TWELVE).unwrap();
+    let stellar = phrase
+        .derive_key(&"m/44'/148'/0'".parse().unwrap(), None)
+        .unwrap();
+    assert_eq!(stellar.curve(), Curve::Ed25519);
+    assert_eq!(
+        stellar.as_ed25519().unwrap().public().to_string().as_str(),
+        "GDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUJUJ6"
+    );
+    assert!(stellar.as_secp256k1().is_none());
+
+    let evm = SeedPhrase::new(phrase.seed_phrase.clone(), Curve::Secp256k1)
+        .derive_key(&"m/44'/60'/0'/0/0".parse().unwrap(), None)
+        .unwrap();
+    assert!(matches!(evm, CurveKey::Secp256k1(_)));
+    assert_eq!(evm.public_key().len(), 33);
+}
+
+#[test]
+fn invalid_curve_and_path() {
+    let phrase = SeedPhrase::from_seed_phrase(TWELVE).unwrap();
+    assert!(matches!(
+        phrase.derive_key(&"m/44'/148'/0".parse().unwrap(), None),
+        Err(Error::InvalidPath(PathError::InvalidSegment {
+            position: 3,
+            reason: SegmentError::NotHardened,
+            ..
+        }))
+    ));
+
+    // Stellar keys are ed25519 only
+    let phrase = phrase.with_curve(Curve::Secp256k1);
+    assert!(matches!(
+        phrase.from_path_index(0, None),
+        Err(Error::UnsupportedCurve(Curve::Secp256k1))
+    ));
+    assert!(matches!(
+        phrase.derived_root(None),
+        Err(Error::UnsupportedCurve(Curve::Secp256k1))
+    ));
+
+    assert!(matches!(
+        Secp256k1KeyPair::from_bytes([0; 32]),
+        Err(Error::InvalidSecretKey)
+    ));
+    assert!(matches!(
+        Secp256k1KeyPair::from_bytes([0xff; 32]),
+        Err(Error::InvalidSecretKey)
+    ));
+}
+
+#[test]
+fn bip32_paths() {
+    let path: Bip32Path = "m/44h/60H/0'/0/7".parse().unwrap();
+    assert_eq!(path.to_string(), "m/44'/60'/0'/0/7");
+    assert_eq!(
+        path.indexes(),
+        &[0x8000_002c, 0x8000_003c, 0x8000_0000, 0, 7]
+    );
+    assert!(!path.is_hardened());
+    assert!(DerivationPath::try_from(&path).is_err());
+
+    let stellar = DerivationPath::stellar_account(3).unwrap();
+    let path = Bip32Path::from(stellar.clone());
+    assert_eq!(path.to_string(), "m/44'/148'/3'");
+    assert!(path.is_hardened());
+    assert_eq!(DerivationPath::try_from(&path).unwrap(), stellar);
+    assert_eq!("m".parse::<Bip32Path>().unwrap().depth(), 0);
+
+    assert!(matches!(
+        "44/0".parse::<Bip32Path>(),
+        Err(PathError::MissingRoot(_))
+    ));
+    assert!(matches!(
+        "m/2147483648".parse::<Bip32Path>(),
+        Err(PathError::InvalidSegment {
+            reason: SegmentError::OutOfRange,
+            ..
+        })
+    ));
+}
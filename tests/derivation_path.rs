@@ -0,0 +1,107 @@
+mod common;
+
+use common::TWELVE;
+use sep5::{error::SegmentError, DerivationPath, Error, PathError, SeedPhrase};
+
+#[test]
+fn parse_and_display() {
+    let path: DerivationPath = "m/44'/148'/0'".parse().unwrap();
+    assert_eq!(path.indexes(), &[44, 148, 0]);
+    assert_eq!(path.to_string(), "m/44'/148'/0'");
+    assert_eq!(path, DerivationPath::stellar_account(0).unwrap());
+
+    let path: DerivationPath = "m/44h/148H/7'".parse().unwrap();
+    assert_eq!(path.to_string(), "m/44'/148'/7'");
+
+    let root: DerivationPath = "m".parse().unwrap();
+    assert_eq!(root.depth(), 0);
+    assert_eq!(root.to_string(), "m");
+}
+
+#[test]
+fn build_from_indexes() {
+    let path = DerivationPath::new(&[44, 148, 3]).unwrap();
+    assert_eq!(path, DerivationPath::stellar().child(3).unwrap());
+    assert_eq!(path.depth(), 3);
+
+    let err = DerivationPath::new(&[44, 148, 1 << 31]).unwrap_err();
+    assert!(matches!(
+        err,
+        PathError::InvalidSegment {
+            position: 3,
+            reason: SegmentError::OutOfRange,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn parse_errors() {
+    let reason = |s: &str| match s.parse::<DerivationPath>().unwrap_err() {
+        PathError::InvalidSegment {
+            position,
+            segment,
+            reason,
+            ..
+        } => (position, segment, reason),
+        e => panic!("unexpected error {e:?}"),
+    };
+    assert_eq!(
+        reason("m/44'/148'/0"),
+        (3, "0".to_string(), SegmentError::NotHardened)
+    );
+    assert_eq!(reason("m/44'//0'"), (2, String::new(), SegmentError::Empty));
+    assert_eq!(
+        reason("m/44'/abc'"),
+        (2, "abc'".to_string(), SegmentError::NotANumber)
+    );
+    assert_eq!(
+        reason("m/44'/-1'"),
+        (2, "-1'".to_string(), SegmentError::NotANumber)
+    );
+    assert_eq!(
+        reason("m/2147483648'"),
+        (1, "2147483648'".to_string(), SegmentError::OutOfRange)
+    );
+    assert_eq!(
+        reason("m/44'/148'/"),
+        (3, String::new(), SegmentError::Empty)
+    );
+    assert!(matches!(
+        "44'/148'".parse::<DerivationPath>(),
+        Err(PathError::MissingRoot(_))
+    ));
+
+    let err = "m/44'/148'/0".parse::<DerivationPath>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid segment 3 (\"0\") in derivation path \"m/44'/148'/0\": ed25519 can only derive hardened indexes, add a trailing '"
+    );
+}
+
+#[test]
+fn derive_with_typed_path() {
+    let phrase: SeedPhrase = TWELVE.parse().unwrap();
+    let path: DerivationPath = "m/44'/148'/1'".parse().unwrap();
+    let key_pair = phrase.from_path(&path, None).unwrap();
+    assert_eq!(
+        key_pair.public().to_string(),
+        "GBAW5XGWORWVFE2XTJYDTLDHXTY2Q2MO73HYCGB3XMFMQ562Q2W2GJQX"
+    );
+    assert_eq!(
+        key_pair.public(),
+        phrase.from_path_string("/1'", None).unwrap().public()
+    );
+
+    assert!(matches!(
+        phrase.from_path_string("/1", None),
+        Err(Error::InvalidPath(PathError::InvalidSegment {
+            reason: SegmentError::NotHardened,
+            ..
+        }))
+    ));
+    assert!(matches!(
+        phrase.from_path_index(1 << 31, None),
+        Err(Error::InvalidIndex { .. })
+    ));
+}
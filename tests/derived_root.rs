@@ -0,0 +1,53 @@
+mod common;
+
+use common::TWELVE;
+use sep5::SeedPhrase;
+
+/// Phrases and passphrases of the SEP-5 test vectors
+const PHRASES: &[(&str, Option<&str>)] = &[
+    (
+        TWELVE,
+        None,
+    ),
+    (
+        "cable spray genius state float twenty onion head street palace net private method loan turn phrase state blanket interest dry amazing dress blast tube",
+        Some("p4ssphr4se"),
+    ),
+];
+
+#[test]
+fn matches_seed_phrase() {
+    for (phrase, passphrase) in PHRASES {
+        let phrase: SeedPhrase = phrase.parse().unwrap();
+        let root = phrase.derived_root(*passphrase).unwrap();
+        assert_eq!(
+            root.empty_key().private().0,
+            phrase.empty_key(*passphrase).unwrap().private().0
+        );
+        for i in 0..10 {
+            let expected = phrase.from_path_index(i, *passphrase).unwrap();
+            let key_pair = root.from_path_index(i).unwrap();
+            assert_eq!(key_pair.public(), expected.public());
+            assert_eq!(key_pair.private().0, expected.private().0);
+            let key_pair = root.from_path_string(&format!("/{i}'")).unwrap();
+            assert_eq!(key_pair.public(), expected.public());
+        }
+    }
+}
+
+#[test]
+fn first_account() {
+    let (phrase, passphrase) = PHRASES[0];
+    let root = SeedPhrase::from_seed_phrase(phrase)
+        .unwrap()
+        .derived_root(passphrase)
+        .unwrap();
+    assert_eq!(
+        root.from_path_index(0)
+            .unwrap()
+            .public()
+            .to_string()
+            .as_str(),
+        "GDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUJUJ6"
+    );
+}
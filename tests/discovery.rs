@@ -0,0 +1,141 @@
+mod common;
+
+use common::TWELVE;
+use sep5::{discovery::DEFAULT_GAP_LIMIT, MemoryOracle, SeedPhrase};
+use stellar_strkey::ed25519::PublicKey;
+
+fn accounts(range: std::ops::Range<usize>) -> Vec<PublicKey> {
+    SeedPhrase::from_seed_phrase(TWELVE)
+        .unwrap()
+        .derive_range(range, None)
+        .unwrap()
+        .iter()
+        .map(|key_pair| key_pair.public())
+        .collect()
+}
+
+fn indexes(found: &[(usize, sep5::KeyPair)]) -> Vec<usize> {
+    found.iter().map(|(n, _)| *n).collect()
+}
+
+#[test]
+fn gap_limit() {
+    let accounts = accounts(0..10);
+    let oracle: MemoryOracle = [0, 2, 5].iter().map(|n| accounts[*n]).collect();
+    let root = SeedPhrase::from_seed_phrase(TWELVE)
+        .unwrap()
+        .derived_root(None)
+        .unwrap();
+
+    let found = root.discover_accounts(&oracle, 3).unwrap();
+    assert_eq!(indexes(&found), [0, 2, 5]);
+    assert_eq!(found[1].1.public(), accounts[2]);
+    assert_eq!(
+        indexes(&root.discover_accounts(&oracle, 2).unwrap()),
+        [0, 2]
+    );
+    assert_eq!(
+        indexes(&root.discover_accounts(&oracle, DEFAULT_GAP_LIMIT).unwrap()),
+        [0, 2, 5]
+    );
+    assert!(matches!(
+        root.discover_accounts(&oracle, 0),
+        Err(sep5::Error::ZeroGapLimit)
+    ));
+    assert!(root
+        .discover_accounts(&MemoryOracle::new(), DEFAULT_GAP_LIMIT)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn seed_phrase_discovery() {
+    let accounts = accounts(0..2);
+    let mut oracle = MemoryOracle::new();
+    oracle.insert(accounts[1]);
+    let phrase = SeedPhrase::from_seed_phrase(TWELVE).unwrap();
+    assert_eq!(
+        indexes(&phrase.discover_accounts(&oracle, 1, None).unwrap()),
+        Vec::<usize>::new()
+    );
+    assert_eq!(
+        indexes(&phrase.discover_accounts(&oracle, 2, None).unwrap()),
+        [1]
+    );
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_oracle() {
+    use sep5::Error;
+
+    let accounts = accounts(0..4);
+    let json = format!(
+        r#"["{}", "{}"]"#,
+        accounts[0].to_string().as_str(),
+        accounts[3].to_string().as_str()
+    );
+    let path = std::env::temp_dir().join(format!("sep5-oracle-{}.json", std::process::id()));
+    std::fs::write(&path, &json).unwrap();
+    let oracle = MemoryOracle::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let found = SeedPhrase::from_seed_phrase(TWELVE)
+        .unwrap()
+        .discover_accounts(&oracle, 3, None)
+        .unwrap();
+    assert_eq!(indexes(&found), [0, 3]);
+
+    assert!(matches!(
+        MemoryOracle::from_json(r#"["GABC"]"#),
+        Err(Error::InvalidPublicKey)
+    ));
+    assert!(matches!(MemoryOracle::from_json("{}"), Err(Error::Json(_))));
+}
+
+#[cfg(feature = "horizon")]
+#[test]
+fn horizon_oracle() {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+    };
+
+    use sep5::{AccountOracle, Error, HorizonOracle};
+
+    let accounts = accounts(0..3);
+    let existing = accounts[1].to_string().as_str().to_string();
+    let failing = accounts[2].to_string().as_str().to_string();
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        for stream in listener.incoming().take(3) {
+            let mut stream = stream.unwrap();
+            let mut request_line = String::new();
+            BufReader::new(&stream)
+                .read_line(&mut request_line)
+                .unwrap();
+            let status = if request_line.contains(&format!("/accounts/{existing} ")) {
+                "200 OK"
+            } else if request_line.contains(&failing) {
+                "500 Internal Server Error"
+            } else {
+                "404 Not Found"
+            };
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{{}}"
+            )
+            .unwrap();
+        }
+    });
+
+    let oracle = HorizonOracle::new(&url);
+    assert!(!oracle.account_exists(&accounts[0]).unwrap());
+    assert!(oracle.account_exists(&accounts[1]).unwrap());
+    assert!(matches!(
+        oracle.account_exists(&accounts[2]),
+        Err(Error::Oracle(_))
+    ));
+    server.join().unwrap();
+}
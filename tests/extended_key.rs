@@ -0,0 +1,106 @@
+mod common;
+
+use common::TWELVE;
+use sep5::{DerivationPath, Error, ExtendedKey, PathError, SeedPhrase};
+
+/// Ed25519 test vector 1 of SLIP-10
+#[test]
+fn slip10_vectors() {
+    let seed: Vec<u8> = (0..16).collect();
+    for (path, chain_code, private_key) in [
+        (
+            "m",
+            "90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb",
+            "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7",
+        ),
+        (
+            "m/0'",
+            "8b59aa11380b624e81507a27fedda59fea6d0b779a778918a2fd3590e16e9c69",
+            "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3",
+        ),
+        (
+            "m/0'/1'",
+            "a320425f77d1b5c2505a6b1b27382b37368ee640e3557c315416801243552f14",
+            "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2",
+        ),
+        (
+            "m/0'/1'/2'/2'/1000000000'",
+            "68789923a0cac2cd5a29172a475fe9e0fb14cd6adb5ad98a3fa70333e7afa230",
+            "8f94d394a8e8fd6b1bc2f3f49f5c47e385281d5c17e65324b0f62483e37e8793",
+        ),
+    ] {
+        let path: DerivationPath = path.parse().unwrap();
+        let key = ExtendedKey::from_seed_path(&seed, &path).unwrap();
+        assert_eq!(key.path(), &path);
+        assert_eq!(hex::encode(key.chain_code()), chain_code);
+        assert_eq!(hex::encode(key.key_pair().private().0), private_key);
+    }
+}
+
+#[test]
+fn base58_round_trip() {
+    let seed: Vec<u8> = (0..16).collect();
+    let key = ExtendedKey::from_seed_path(&seed, &"m/0'/1'".parse().unwrap()).unwrap();
+    let encoded = "2tzK17EujHCBkf7imtEc1nSHHJNy8KMAEnZeuAnFBSg6yikawJTiTd4ZByeofCnKQyfp2WP6osFRYxs5hASz1JyVBKJiMeqqF2y82YkWzw";
+    assert_eq!(key.to_base58().as_str(), encoded);
+
+    let imported: ExtendedKey = encoded.parse().unwrap();
+    assert_eq!(imported.path(), key.path());
+    assert_eq!(imported.chain_code(), key.chain_code());
+    assert_eq!(imported.public(), key.public());
+
+    let mut corrupted = encoded.to_string();
+    corrupted.replace_range(10..11, "2");
+    for invalid in ["", "1", corrupted.as_str(), "0OIl"] {
+        assert!(matches!(
+            ExtendedKey::from_base58(invalid),
+            Err(Error::InvalidExtendedKey)
+        ));
+    }
+}
+
+#[test]
+fn derive_sub_tree() {
+    let phrase = SeedPhrase::from_seed_phrase(TWELVE).unwrap();
+    let account = phrase
+        .extended_key(&DerivationPath::stellar_account(5).unwrap(), None)
+        .unwrap();
+    assert_eq!(account.path().to_string(), "m/44'/148'/5'");
+
+    // a service holding the exported node derives the same keys as the phrase
+    let imported = ExtendedKey::from_base58(&account.to_base58()).unwrap();
+    let child = imported.derive(7).unwrap();
+    assert_eq!(child.path().to_string(), "m/44'/148'/5'/7'");
+    assert_eq!(
+        child.public(),
+        phrase.from_path_string("/5'/7'", None).unwrap().public()
+    );
+
+    let root = phrase.derived_root(None).unwrap().extended_key();
+    assert_eq!(root.path(), &DerivationPath::stellar());
+    assert_eq!(
+        root.derive(0).unwrap().public().to_string().as_str(),
+        "GDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUJUJ6"
+    );
+    assert!(root.derive(DerivationPath::MAX_INDEX + 1).is_err());
+}
+
+#[test]
+fn max_depth() {
+    let deepest = DerivationPath::new(&[0; ExtendedKey::MAX_DEPTH]).unwrap();
+    let key = ExtendedKey::from_seed_path(&[0; 16], &deepest).unwrap();
+    assert!(matches!(
+        key.derive(0),
+        Err(Error::InvalidPath(PathError::TooDeep(_)))
+    ));
+    let imported = ExtendedKey::from_base58(&key.to_base58()).unwrap();
+    assert_eq!(imported.path(), &deepest);
+}
+
+#[test]
+fn debug_redacts_secrets() {
+    let key = ExtendedKey::from_seed(&[0; 16]);
+    let debug = format!("{key:?}");
+    assert!(debug.contains("[REDACTED]"));
+    assert!(!debug.contains(&hex::encode(key.key_pair().private().0)));
+}
@@ -0,0 +1,146 @@
+#![cfg(feature = "keystore")]
+
+use sep5::{
+    keystore::{KdfParams, Keystore},
+    DerivationPath, Error, KeystoreError, Language, SeedPhrase,
+};
+
+// cheap parameters to keep the tests fast
+const PARAMS: KdfParams = KdfParams {
+    memory_kib: 64,
+    iterations: 1,
+    parallelism: 1,
+};
+
+fn keystore() -> Keystore {
+    let seed_phrase = SeedPhrase::from_entropy_in(&[3; 16], Language::Japanese).unwrap();
+    Keystore::new(seed_phrase).with_hint(DerivationPath::stellar_account(7).unwrap())
+}
+
+fn keystore_error(result: Result<Keystore, Error>) -> KeystoreError {
+    match result {
+        Err(Error::Keystore(err)) => err,
+        other => panic!("expected a keystore error, got {other:?}"),
+    }
+}
+
+#[test]
+fn roundtrip() {
+    let keystore = keystore();
+    let document = keystore.encrypt_with("correct horse", PARAMS).unwrap();
+    assert!(!document.contains(keystore.seed_phrase.phrase()));
+
+    let loaded = Keystore::decrypt(&document, "correct horse").unwrap();
+    assert_eq!(loaded.seed_phrase.phrase(), keystore.seed_phrase.phrase());
+    assert_eq!(loaded.seed_phrase.language(), Language::Japanese);
+    assert_eq!(loaded.hint.unwrap().to_string(), "m/44'/148'/7'");
+}
+
+/// Path in the temporary directory unique to this test run
+fn temp_path(name: &str) -> std::path::PathBuf {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("sep5-{name}-{}-{nanos}.json", std::process::id()))
+}
+
+#[test]
+fn save_and_load() {
+    let path = temp_path("keystore");
+    let keystore = Keystore::new(SeedPhrase::from_entropy(&[9; 32]).unwrap());
+    keystore.save(&path, "hunter2").unwrap();
+    let document = std::fs::read_to_string(&path).unwrap();
+    assert!(document.contains("\"memory_kib\": 19456"));
+    let loaded = Keystore::load(&path, "hunter2").unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.seed_phrase.phrase(), keystore.seed_phrase.phrase());
+    assert!(loaded.hint.is_none());
+}
+
+#[test]
+fn errors() {
+    let document = keystore().encrypt_with("correct horse", PARAMS).unwrap();
+    assert_eq!(
+        keystore_error(Keystore::decrypt(&document, "wrong horse")),
+        KeystoreError::WrongPassword
+    );
+
+    let mut json: serde_json::Value = serde_json::from_str(&document).unwrap();
+    let ciphertext = json["ciphertext"].as_str().unwrap().to_string();
+    json["ciphertext"] = ciphertext.replacen(&ciphertext[..4], "AAAA", 1).into();
+    assert_eq!(
+        keystore_error(Keystore::decrypt(&json.to_string(), "correct horse")),
+        KeystoreError::Corrupted
+    );
+    assert_eq!(
+        keystore_error(Keystore::decrypt(&document[..100], "correct horse")),
+        KeystoreError::Corrupted
+    );
+
+    json["version"] = 2.into();
+    assert_eq!(
+        keystore_error(Keystore::decrypt(&json.to_string(), "correct horse")),
+        KeystoreError::UnsupportedVersion(2)
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn save_restricts_existing_file() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = temp_path("keystore-existing");
+    std::fs::write(&path, "").unwrap();
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+    keystore().save(&path, "hunter2").unwrap();
+    let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(mode & 0o777, 0o600);
+}
+
+#[test]
+fn header_is_authenticated() {
+    let document = keystore().encrypt_with("correct horse", PARAMS).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&document).unwrap();
+    for (field, value) in [
+        ("salt", serde_json::Value::from("AAAAAAAAAAAAAAAAAAAAAA==")),
+        ("iterations", 2.into()),
+        ("memory_kib", 128.into()),
+    ] {
+        let mut json = json.clone();
+        json["kdf"][field] = value;
+        assert_eq!(
+            keystore_error(Keystore::decrypt(&json.to_string(), "correct horse")),
+            KeystoreError::Corrupted,
+            "{field}"
+        );
+    }
+}
+
+#[test]
+fn kdf_ceiling() {
+    let document = keystore().encrypt_with("correct horse", PARAMS).unwrap();
+    let mut json: serde_json::Value = serde_json::from_str(&document).unwrap();
+    json["kdf"]["memory_kib"] = (KdfParams::MAX_MEMORY_KIB + 1).into();
+    assert_eq!(
+        keystore_error(Keystore::decrypt(&json.to_string(), "correct horse")),
+        KeystoreError::InvalidKdfParams
+    );
+
+    for params in [
+        KdfParams {
+            iterations: KdfParams::MAX_ITERATIONS + 1,
+            ..PARAMS
+        },
+        KdfParams {
+            parallelism: KdfParams::MAX_PARALLELISM + 1,
+            ..PARAMS
+        },
+    ] {
+        assert!(matches!(
+            keystore().encrypt_with("correct horse", params),
+            Err(Error::Keystore(KeystoreError::InvalidKdfParams))
+        ));
+    }
+}
@@ -0,0 +1,90 @@
+use sep5::{language, Language, SeedPhrase};
+
+// https://github.com/bip32JP/bip32JP.github.io/blob/master/test_JP_BIP39.json
+const JAPANESE: &str = "あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あおぞら";
+const JAPANESE_PASSPHRASE: &str = "㍍ガバヴァぱばぐゞちぢ十人十色";
+const JAPANESE_SEED: &str = "a262d6fb6122ecf45be09c50492b31f92e9beb7d9a845987a02cefda57a15f9c467a17872029a9e92299b5cbdf306e3a0ee620245cbd508959b6cb7ca637bd55";
+
+#[test]
+fn japanese() {
+    let phrase: SeedPhrase = JAPANESE.parse().unwrap();
+    assert_eq!(phrase.language(), Language::Japanese);
+    assert_eq!(phrase.display_phrase(), JAPANESE);
+    assert_eq!(
+        format!("{:x}", phrase.to_seed(Some(JAPANESE_PASSPHRASE))),
+        JAPANESE_SEED
+    );
+
+    let from_entropy = SeedPhrase::from_entropy_in(&[0; 16], Language::Japanese).unwrap();
+    assert_eq!(from_entropy.phrase(), phrase.phrase());
+    assert_eq!(from_entropy.display_phrase(), JAPANESE);
+
+    // ASCII spaces are accepted too
+    let spaced = SeedPhrase::from_seed_phrase(&JAPANESE.replace('\u{3000}', " ")).unwrap();
+    assert_eq!(spaced.phrase(), phrase.phrase());
+}
+
+#[test]
+fn spanish_accepts_composed_and_decomposed_input() {
+    let nfc = "ábaco ábaco ábaco ábaco ábaco ábaco ábaco ábaco ábaco ábaco ábaco abierto";
+    let nfd = nfc.replace('á', "a\u{301}");
+    for input in [nfc, nfd.as_str()] {
+        let phrase = SeedPhrase::from_seed_phrase(input).unwrap();
+        assert_eq!(phrase.language(), Language::Spanish);
+        assert_eq!(phrase.display_phrase(), nfc);
+        assert_eq!(
+            format!("{:x}", phrase.to_seed(None)),
+            "fdfe9b7c7a5e5079bb36d6381838867a34358db0a0307d060adbaf5edadb08b0a87c06ede1a96afd8566ef499792ffcbd37f43f6f554fa344138660eacdefcf8"
+        );
+    }
+}
+
+#[test]
+fn korean_and_chinese() {
+    let entropy = (0..16).collect::<Vec<u8>>();
+    for (language, display, seed) in [
+        (
+            Language::Korean,
+            "가격 걱정 심부름 걱정 별도 갈색 기법 기운 경력 산길 미술 기념",
+            "c84d23b603720bc67db1b1f5f1cbfc82b760736ad8069bf283c8d5d2a5b1e2075e73208fbe8763500b572839ff3c7827917a7d8eec19b2732152f84b0ace5b70",
+        ),
+        (
+            Language::ChineseSimplified,
+            "的 三 欧 三 考 于 据 保 量 损 破 战",
+            "9859899437d054276ba8301d0a27b0c0c67c6e2863d68ed8d52e44c5ed9e0cc4132a5f6ba37c4ee8a2f2bbc498293a642c9ff497fff1f5f546cae2c165e0f089",
+        ),
+    ] {
+        let phrase = SeedPhrase::from_entropy_in(&entropy, language).unwrap();
+        assert_eq!(phrase.display_phrase(), display);
+        assert_eq!(format!("{:x}", phrase.to_seed(None)), seed);
+
+        let parsed: SeedPhrase = display.parse().unwrap();
+        assert_eq!(parsed.language(), language);
+        assert_eq!(
+            parsed.empty_key(None).unwrap().public(),
+            phrase.empty_key(None).unwrap().public()
+        );
+    }
+}
+
+#[test]
+fn detect() {
+    assert_eq!(
+        language::detect("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"),
+        Language::English
+    );
+    // "abandon" is in both the English and French wordlists
+    let french = SeedPhrase::from_entropy_in(&[1; 16], Language::French).unwrap();
+    assert_eq!(language::detect(french.phrase()), Language::French);
+    // mostly Japanese, one typo
+    assert_eq!(
+        language::detect("あいこくしん あいこくしん typo"),
+        Language::Japanese
+    );
+
+    let err = SeedPhrase::from_seed_phrase_in(JAPANESE, Language::English).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("Invalid seed phrase: unknown word at position 1"));
+    assert!(!format!("{err} {err:?}").contains("あいこくしん"));
+}
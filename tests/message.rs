@@ -0,0 +1,39 @@
+mod common;
+
+use sep5::{key_pair::message_hash, Signature, VerifyMessage};
+use stellar_strkey::ed25519::PublicKey;
+
+// https://github.com/stellar/stellar-protocol/blob/master/ecosystem/sep-0053.md
+const SEP53_ADDRESS: &str = "GBXFXNDLV4LSWA4VB7YIL5GBD7BVNR22SGBTDKMO2SBZZHDXSKZYCP7L";
+
+#[test]
+fn sep53_vectors() {
+    let public_key: PublicKey = SEP53_ADDRESS.parse().unwrap();
+    for (message, signature) in [
+        (
+            "Hello, World!".as_bytes().to_vec(),
+            "fO5dbYhXUhBMhe6kId/cuVq/AfEnHRHEvsP8vXh03M1uLpi5e46yO2Q8rEBzu3feXQewcQE5GArp88u6ePK6BA==",
+        ),
+        (
+            "こんにちは、世界！".as_bytes().to_vec(),
+            "CDU265Xs8y3OWbB/56H9jPgUss5G9A0qFuTqH2zs2YDgTm+++dIfmAEceFqB7bhfN3am59lCtDXrCtwH2k1GBA==",
+        ),
+    ] {
+        let signature: Signature = signature.parse().unwrap();
+        public_key.verify_message(&message, &signature).unwrap();
+        assert!(public_key.verify_message(b"other", &signature).is_err());
+    }
+}
+
+#[test]
+fn sign_message() {
+    let key = common::account(0);
+    let message = b"login to example.com at 1700000000";
+    let signature = key.sign_message(message);
+    key.verify_message(message, &signature).unwrap();
+    key.public().verify_message(message, &signature).unwrap();
+
+    // the prefixed hash is signed, not the raw message
+    assert!(key.verify(message, &signature).is_err());
+    key.verify(&message_hash(message), &signature).unwrap();
+}
@@ -0,0 +1,91 @@
+mod common;
+
+use common::TWELVE;
+use sep5::{Curve, Error, PathScheme, SeedPhrase};
+use stellar_strkey::ed25519::PublicKey;
+
+const VECTORS: &[(PathScheme, &[&str])] = &[
+    (
+        PathScheme::Sep5,
+        &[
+            "GDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUJUJ6",
+            "GBAW5XGWORWVFE2XTJYDTLDHXTY2Q2MO73HYCGB3XMFMQ562Q2W2GJQX",
+            "GAY5PRAHJ2HIYBYCLZXTHID6SPVELOOYH2LBPH3LD4RUMXUW3DOYTLXW",
+        ],
+    ),
+    (
+        PathScheme::Bip44,
+        &[
+            "GA3G5W2SUZIJSMSQP2MC55DXJBA2EK5BVZDUYTZN4H3WU76U4KEIDOZ4",
+            "GB5ACO7UU733HFWIHKEHQX5BEIU3OBR35L3JGZUVTQ426UHTCGIMXZBK",
+            "GDWNWTC5RI4LPN5IG5B322FO5INOVWKOJOVREGVVLW35YQSVKPMXV3JG",
+        ],
+    ),
+];
+
+#[test]
+fn scheme_vectors() {
+    let phrase = SeedPhrase::from_seed_phrase(TWELVE).unwrap();
+    for (scheme, addresses) in VECTORS {
+        for (n, address) in addresses.iter().enumerate() {
+            let key_pair = phrase.from_scheme_index(*scheme, n, None).unwrap();
+            assert_eq!(
+                key_pair.public().to_string().as_str(),
+                *address,
+                "{} account {n}",
+                scheme.name()
+            );
+        }
+    }
+}
+
+#[test]
+fn scheme_paths() {
+    for (scheme, path) in [
+        (PathScheme::Sep5, "m/44'/148'/7'"),
+        (PathScheme::Bip44, "m/44'/148'/0'/0'/7'"),
+    ] {
+        assert_eq!(scheme.path(7).unwrap().to_string(), path);
+        assert_eq!(PathScheme::from_name(scheme.name()), Some(scheme));
+    }
+    assert!(matches!(
+        PathScheme::Bip44.path(1 << 31),
+        Err(Error::InvalidIndex { path }) if path == "m/44'/148'/0'/0'/2147483648'"
+    ));
+    assert_eq!(PathScheme::from_name("trezor"), None);
+}
+
+#[test]
+fn discover_scheme() {
+    let phrase = SeedPhrase::from_seed_phrase(TWELVE).unwrap();
+    for (scheme, n) in [
+        (PathScheme::Sep5, 2),
+        (PathScheme::Bip44, 1),
+        (PathScheme::Bip44, 0),
+    ] {
+        let (_, addresses) = VECTORS.iter().find(|(s, _)| *s == scheme).unwrap();
+        let public_key = PublicKey::from_string(addresses[n]).unwrap();
+        assert_eq!(
+            phrase.discover_scheme(&public_key, None, 2).unwrap(),
+            Some((scheme, n))
+        );
+    }
+
+    // beyond `max_index`
+    let public_key = PublicKey::from_string(VECTORS[1].1[2]).unwrap();
+    assert_eq!(phrase.discover_scheme(&public_key, None, 1).unwrap(), None);
+    // another passphrase
+    assert_eq!(
+        phrase
+            .discover_scheme(&public_key, Some("passphrase"), 2)
+            .unwrap(),
+        None
+    );
+
+    assert!(matches!(
+        phrase
+            .with_curve(Curve::Secp256k1)
+            .discover_scheme(&public_key, None, 2),
+        Err(Error::UnsupportedCurve(Curve::Secp256k1))
+    ));
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use common::TWELVE;
+use std::sync::atomic::AtomicBool;
+
+use sep5::{
+    recovery::{Progress, Recovery},
+    Error,
+};
+use stellar_strkey::ed25519::PublicKey;
+
+#[test]
+fn recover_checksum_candidates() {
+    let recovery =
+        Recovery::new("illness spike retreat truth genius clock brain pass fit cave bargain ?")
+            .unwrap();
+    assert_eq!(recovery.candidates(), Some(2048));
+    let recovered = recovery.run().unwrap();
+    // 12 words carry a 4 bit checksum
+    assert_eq!(recovered.len(), 128);
+    assert!(recovered.iter().all(|r| r.account.is_none()));
+    assert!(recovered.iter().any(|r| r.seed_phrase.phrase() == TWELVE));
+}
+
+#[test]
+fn recover_with_target_and_progress() {
+    let target =
+        PublicKey::from_string("GAY5PRAHJ2HIYBYCLZXTHID6SPVELOOYH2LBPH3LD4RUMXUW3DOYTLXW").unwrap();
+    let mut reports = Vec::new();
+    let recovered =
+        Recovery::new("illness spike retreat truth genius clock brain pass fit cave bargain ?")
+            .unwrap()
+            .target(target, 2)
+            .on_progress(|progress| reports.push(*progress))
+            .run()
+            .unwrap();
+    assert_eq!(recovered.len(), 1);
+    assert_eq!(recovered[0].seed_phrase.phrase(), TWELVE);
+    let (index, path) = recovered[0].account.as_ref().unwrap();
+    assert_eq!(*index, 2);
+    assert_eq!(path.to_string(), "m/44'/148'/2'");
+    assert_eq!(
+        reports.last(),
+        Some(&Progress {
+            checked: 2048,
+            total: 2048,
+            matches: 1
+        })
+    );
+    assert_eq!(reports.len(), 2);
+}
+
+#[test]
+fn cancel_and_invalid_input() {
+    let cancel = AtomicBool::new(true);
+    let result = Recovery::new("? spike retreat truth genius clock brain pass fit cave bargain ?")
+        .unwrap()
+        .cancel_on(&cancel)
+        .run();
+    assert!(matches!(result, Err(Error::Cancelled)));
+
+    let Err(Error::InvalidSeedPhrase(report)) =
+        Recovery::new("illness spik ? truth genius clock brain pass fit cave bargain toe")
+    else {
+        panic!("expected an invalid seed phrase");
+    };
+    assert_eq!(report.unknown_words.len(), 1);
+    assert_eq!(report.unknown_words[0].position, 1);
+    assert!(Recovery::new("illness spike ?").is_err());
+}
+
+#[test]
+fn too_many_missing_words() {
+    let recovery = Recovery::new("illness spike ? ? ? ? ? ? fit cave bargain toe").unwrap();
+    assert_eq!(recovery.candidates(), None);
+    assert!(matches!(recovery.run(), Err(Error::TooManyMissingWords(6))));
+    assert_eq!(
+        Recovery::new("illness spike ? ? ? ? ? pass fit cave bargain toe")
+            .unwrap()
+            .candidates(),
+        Some(1 << 55)
+    );
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use sep5::{
+    key_pair::verify,
+    signer::{self, payload_signature_hint},
+    Error,
+};
+use stellar_strkey::ed25519::SignedPayload;
+
+#[test]
+fn signed_payload() {
+    let key_pair = common::account(0);
+    let full: Vec<u8> = (0..64).collect();
+    for (payload, address, hint) in [
+        (
+            &[0xff][..],
+            "PDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUIAAAAAA76AAAAA42U",
+            "b171e544",
+        ),
+        (
+            &[1, 2],
+            "PDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUIAAAAABACAQAACOBO",
+            "4f73e544",
+        ),
+        (
+            &full[1..33],
+            "PDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUIAAAAAQACAQDAQCQMBYIBEFAWDANBYHRAEISCMKBKFQXDAMRUGY4DUPB6IBM5M",
+            "536ffa64",
+        ),
+        (
+            &full,
+            "PDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUIAAAABAAAAICAMCAKBQHBAEQUCYMBUHA6EARCIJRIFIWC4MBSGQ3DQOR4HZAEERCGJBFEYTSQKJKFMWC2LRPGAYTEMZUGU3DOOBZHI5TYPJ6H5QQO",
+            "724cdb7b",
+        ),
+    ] {
+        let signed_payload = key_pair.signed_payload(payload).unwrap();
+        assert_eq!(signed_payload.to_string().as_str(), address);
+        assert_eq!(SignedPayload::from_string(address).unwrap(), signed_payload);
+        assert_eq!(
+            payload_signature_hint(&signed_payload)[..],
+            hex::decode(hint).unwrap()[..]
+        );
+
+        let signature = key_pair.sign_payload(payload).unwrap();
+        verify(&key_pair.public(), payload, &signature).unwrap();
+    }
+}
+
+#[test]
+fn invalid_payload_length() {
+    let key_pair = common::account(0);
+    assert!(matches!(
+        key_pair.signed_payload(&[]),
+        Err(Error::InvalidLength {
+            length: 0,
+            min: 1,
+            max: 64
+        })
+    ));
+    assert!(matches!(
+        key_pair.signed_payload(&[0; 65]),
+        Err(Error::InvalidLength {
+            length: 65,
+            min: 1,
+            max: 64
+        })
+    ));
+    assert!(key_pair.sign_payload(&[0; 65]).is_err());
+}
+
+#[test]
+fn pre_auth_tx() {
+    let hash: [u8; 32] = core::array::from_fn(|i| i as u8);
+    assert_eq!(
+        signer::pre_auth_tx(hash).to_string().as_str(),
+        "TAAACAQDAQCQMBYIBEFAWDANBYHRAEISCMKBKFQXDAMRUGY4DUPB6ULG"
+    );
+}
+
+#[test]
+fn hash_x() {
+    assert_eq!(
+        signer::hash_x(b"hello").to_string().as_str(),
+        "XAWPETN2L6YKGDRG5A5SVRNZ4KPBWFQ6LQP2OQS6OMCDGYUTROMCJ4VO"
+    );
+}
+
+#[cfg(feature = "stellar-xdr")]
+#[test]
+fn sign_payload_decorated() {
+    let key_pair = common::account(0);
+    let payload = [1, 2];
+    let decorated = key_pair.sign_payload_decorated(&payload).unwrap();
+    assert_eq!(decorated.hint.0, [0x4f, 0x73, 0xe5, 0x44]);
+    assert_eq!(
+        &decorated.signature.0[..],
+        &key_pair.sign_payload(&payload).unwrap().to_bytes()[..]
+    );
+}
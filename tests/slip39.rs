@@ -0,0 +1,242 @@
+mod common;
+
+use common::TWELVE;
+use sep5::{
+    slip39::{self, Group},
+    Error, Language, SeedPhrase, Slip39Error,
+};
+
+const PASSPHRASE: &str = "TREZOR";
+
+// https://github.com/trezor/python-shamir-mnemonic/blob/master/vectors.json
+const VALID: &[(&[&str], &str)] = &[
+    // Valid mnemonic without sharing (128 bits)
+    (
+        &[
+            "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard",
+        ],
+        "bb54aac4b89dc868ba37d9cc21b2cece",
+    ),
+    // Basic sharing 2-of-3 (128 bits)
+    (
+        &[
+            "shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang wrist rescue view short owner flip making coding armed",
+            "shadow pistol academic acid actress prayer class unknown daughter sweater depict flip twice unkind craft early superior advocate guest smoking",
+        ],
+        "b43ceb7e57a0ea8766221624d01b0864",
+    ),
+    // Threshold number of groups and members in each group (128 bits, case 1)
+    (
+        &[
+            "eraser senior decision roster beard treat identify grumpy salt index fake aviation theater cubic bike cause research dragon emphasis counter",
+            "eraser senior ceramic snake clay various huge numb argue hesitate auction category timber browser greatest hanger petition script leaf pickup",
+            "eraser senior ceramic shaft dynamic become junior wrist silver peasant force math alto coal amazing segment yelp velvet image paces",
+            "eraser senior ceramic round column hawk trust auction smug shame alive greatest sheriff living perfect corner chest sled fumes adequate",
+            "eraser senior decision smug corner ruin rescue cubic angel tackle skin skunk program roster trash rumor slush angel flea amazing",
+        ],
+        "7c3397a292a5941682d7a4ae2d898d11",
+    ),
+    // Threshold number of groups and members in each group (128 bits, case 2)
+    (
+        &[
+            "eraser senior decision smug corner ruin rescue cubic angel tackle skin skunk program roster trash rumor slush angel flea amazing",
+            "eraser senior beard romp adorn nuclear spill corner cradle style ancient family general leader ambition exchange unusual garlic promise voice",
+            "eraser senior decision scared cargo theory device idea deliver modify curly include pancake both news skin realize vitamins away join",
+        ],
+        "7c3397a292a5941682d7a4ae2d898d11",
+    ),
+    // Threshold number of groups and members in each group (128 bits, case 3)
+    (
+        &[
+            "eraser senior beard romp adorn nuclear spill corner cradle style ancient family general leader ambition exchange unusual garlic promise voice",
+            "eraser senior acrobat romp bishop medical gesture pumps secret alive ultimate quarter priest subject class dictate spew material endless market",
+        ],
+        "7c3397a292a5941682d7a4ae2d898d11",
+    ),
+    // Valid mnemonic without sharing (256 bits)
+    (
+        &[
+            "theory painting academic academic armed sweater year military elder discuss acne wildlife boring employer fused large satoshi bundle carbon diagnose anatomy hamster leaves tracks paces beyond phantom capital marvel lips brave detect luck",
+        ],
+        "989baf9dcaad5b10ca33dfd8cc75e42477025dce88ae83e75a230086a0e00e92",
+    ),
+];
+
+#[test]
+fn valid_vectors() {
+    for (mnemonics, secret) in VALID {
+        let recovered = slip39::combine(mnemonics, PASSPHRASE).unwrap();
+        assert_eq!(hex::encode(&recovered), *secret);
+    }
+}
+
+#[test]
+fn invalid_vectors() {
+    let cases: &[(&[&str], Slip39Error)] = &[
+        // Mnemonic with invalid checksum (128 bits)
+        (
+            &[
+                "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision kidney",
+            ],
+            Slip39Error::InvalidChecksum,
+        ),
+        // Mnemonic with invalid padding (128 bits)
+        (
+            &[
+                "duckling enlarge academic academic email result length solution fridge kidney coal piece deal husband erode duke ajar music cargo fitness",
+            ],
+            Slip39Error::InvalidPadding,
+        ),
+        // Mnemonics with different identifiers (128 bits)
+        (
+            &[
+                "adequate smoking academic acid debut wine petition glen cluster slow rhyme slow simple epidemic rumor junk tracks treat olympic tolerate",
+                "adequate stay academic agency agency formal party ting frequent learn upstairs remember smear leaf damage anatomy ladle market hush corner",
+            ],
+            Slip39Error::MismatchedShares,
+        ),
+        // Mnemonics with duplicate member indices (128 bits)
+        (
+            &[
+                "device stay academic always dive coal antenna adult black exceed stadium herald advance soldier busy dryer daughter evaluate minister laser",
+                "device stay academic always dwarf afraid robin gravity crunch adjust soul branch walnut coastal dream costume scholar mortgage mountain pumps",
+            ],
+            Slip39Error::DuplicateMemberIndex,
+        ),
+        // Mnemonics giving an invalid digest (128 bits)
+        (
+            &[
+                "guilt walnut academic acid deliver remove equip listen vampire tactics nylon rhythm failure husband fatigue alive blind enemy teaspoon rebound",
+                "guilt walnut academic agency brave hamster hobo declare herd taste alpha slim criminal mild arcade formal romp branch pink ambition",
+            ],
+            Slip39Error::InvalidDigest,
+        ),
+        // Threshold number of groups, but insufficient number of members in one group (128 bits)
+        (
+            &[
+                "eraser senior decision shadow artist work morning estate greatest pipeline plan ting petition forget hormone flexible general goat admit surface",
+                "eraser senior beard romp adorn nuclear spill corner cradle style ancient family general leader ambition exchange unusual garlic promise voice",
+            ],
+            Slip39Error::InsufficientShares,
+        ),
+        // Mnemonic with insufficient length
+        (
+            &[
+                "junk necklace academic academic acne isolate join hesitate lunar roster dough calcium chemical ladybug amount mobile glasses verify cylinder",
+            ],
+            Slip39Error::InvalidLength(19),
+        ),
+        // Mnemonic with invalid master secret length
+        (
+            &[
+                "fraction necklace academic academic award teammate mouse regular testify coding building member verdict purchase blind camera duration email prepare spirit quarter",
+            ],
+            Slip39Error::InvalidLength(21),
+        ),
+        // Not from the vectors: the first mnemonic with group index 1 of 1
+        // and its checksum recomputed
+        (
+            &[
+                "duckling enlarge away academic agency result length solution fridge kidney coal piece deal husband erode duke ajar document bishop usual",
+            ],
+            Slip39Error::InvalidGroupIndex,
+        ),
+    ];
+    for (mnemonics, expected) in cases {
+        match slip39::combine(mnemonics, PASSPHRASE) {
+            Err(Error::Slip39(err)) => assert_eq!(&err, expected),
+            other => panic!("expected {expected:?}, got {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn seed_phrase_roundtrip() {
+    let phrase = SeedPhrase::from_seed_phrase(TWELVE).unwrap();
+    let groups = [Group::new(2, 3), Group::new(3, 5)];
+    let shares = phrase.to_slip39(2, &groups, "office").unwrap();
+    assert_eq!(shares.len(), 2);
+    assert_eq!(shares[0].len(), 3);
+    assert_eq!(shares[1].len(), 5);
+
+    let subset = [
+        &shares[0][2],
+        &shares[1][4],
+        &shares[0][0],
+        &shares[1][1],
+        &shares[1][2],
+    ];
+    let recovered = SeedPhrase::from_slip39(&subset, "office").unwrap();
+    assert_eq!(recovered.phrase(), phrase.phrase());
+    assert_eq!(
+        recovered.empty_key(None).unwrap().public(),
+        phrase.empty_key(None).unwrap().public()
+    );
+
+    // a single complete group is not enough
+    assert!(matches!(
+        SeedPhrase::from_slip39(&shares[1], "office"),
+        Err(Error::Slip39(Slip39Error::InsufficientShares))
+    ));
+    // the wrong passphrase silently gives another secret
+    let other = SeedPhrase::from_slip39(&subset, "").unwrap();
+    assert_ne!(other.phrase(), phrase.phrase());
+}
+
+#[test]
+fn seed_phrase_roundtrip_in_language() {
+    let phrase = SeedPhrase::from_entropy_in(&[7; 16], Language::French).unwrap();
+    let shares = phrase.to_slip39(1, &[Group::new(2, 3)], "").unwrap();
+    let subset = [&shares[0][0], &shares[0][2]];
+
+    let recovered = SeedPhrase::from_slip39_in(&subset, "", Language::French).unwrap();
+    assert_eq!(recovered.phrase(), phrase.phrase());
+    assert_eq!(
+        recovered.empty_key(None).unwrap().public(),
+        phrase.empty_key(None).unwrap().public()
+    );
+
+    // the same entropy as English words derives other keys
+    let english = SeedPhrase::from_slip39(&subset, "").unwrap();
+    assert_eq!(english.language(), Language::English);
+    assert_ne!(
+        english.empty_key(None).unwrap().public(),
+        phrase.empty_key(None).unwrap().public()
+    );
+}
+
+#[test]
+fn invalid_split_parameters() {
+    let secret = [0u8; 16];
+    for (secret, group_threshold, groups, expected) in [
+        (
+            &secret[..15],
+            1,
+            vec![Group::new(1, 1)],
+            Slip39Error::InvalidMasterSecretLength(15),
+        ),
+        (
+            &secret[..],
+            2,
+            vec![Group::new(1, 1)],
+            Slip39Error::InvalidGroupThreshold,
+        ),
+        (
+            &secret[..],
+            1,
+            vec![Group::new(1, 2)],
+            Slip39Error::InvalidGroup,
+        ),
+        (
+            &secret[..],
+            1,
+            vec![Group::new(3, 2)],
+            Slip39Error::InvalidGroup,
+        ),
+    ] {
+        match slip39::split(secret, group_threshold, &groups, "", 0, true) {
+            Err(Error::Slip39(err)) => assert_eq!(err, expected),
+            other => panic!("expected {expected:?}, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "stellar-xdr")]
+
+mod common;
+
+use sep5::{key_pair::verify, soroban, transaction::TEST_NETWORK, Error, Signature};
+use stellar_xdr::curr::{
+    AccountId, ContractId, Hash, InvokeContractArgs, Limits, PublicKey, ReadXdr, ScAddress,
+    ScMapEntry, ScSymbol, ScVal, SorobanAddressCredentials, SorobanAuthorizationEntry,
+    SorobanAuthorizedFunction, SorobanAuthorizedInvocation, SorobanCredentials, Uint256, WriteXdr,
+};
+
+fn entry(address: [u8; 32]) -> SorobanAuthorizationEntry {
+    SorobanAuthorizationEntry {
+        credentials: SorobanCredentials::Address(SorobanAddressCredentials {
+            address: ScAddress::Account(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(
+                address,
+            )))),
+            nonce: 42,
+            signature_expiration_ledger: 0,
+            signature: ScVal::Void,
+        }),
+        root_invocation: SorobanAuthorizedInvocation {
+            function: SorobanAuthorizedFunction::ContractFn(InvokeContractArgs {
+                contract_address: ScAddress::Contract(ContractId(Hash([1; 32]))),
+                function_name: ScSymbol("transfer".try_into().unwrap()),
+                args: Default::default(),
+            }),
+            sub_invocations: Default::default(),
+        },
+    }
+}
+
+#[test]
+fn sign_authorization_entry() {
+    let key = common::account(0);
+    let unsigned = entry(key.public().0);
+    let hash =
+        soroban::authorization_hash(&unsigned.root_invocation, 42, 1000, TEST_NETWORK).unwrap();
+    assert_eq!(
+        hex::encode(hash),
+        "6a1118626610acf787e25e4ebb33144081d32ee5689372f1f3896b9acbc0b603"
+    );
+
+    let xdr = unsigned.to_xdr_base64(Limits::none()).unwrap();
+    let signed = key
+        .sign_authorization_entry_xdr(&xdr, TEST_NETWORK, 1000)
+        .unwrap();
+    let signed = SorobanAuthorizationEntry::from_xdr_base64(signed, Limits::none()).unwrap();
+    let SorobanCredentials::Address(credentials) = signed.credentials else {
+        unreachable!()
+    };
+    assert_eq!(credentials.signature_expiration_ledger, 1000);
+    let ScVal::Vec(Some(signatures)) = credentials.signature else {
+        panic!("expected a vector of signatures");
+    };
+    let [ScVal::Map(Some(map))] = signatures.0.as_slice() else {
+        panic!("expected a single signature map");
+    };
+    let [ScMapEntry {
+        key: ScVal::Symbol(public_key_name),
+        val: ScVal::Bytes(public_key),
+    }, ScMapEntry {
+        key: ScVal::Symbol(signature_name),
+        val: ScVal::Bytes(signature),
+    }] = map.0.as_slice()
+    else {
+        panic!("unexpected signature map {map:?}");
+    };
+    assert_eq!(public_key_name.0.as_slice(), b"public_key");
+    assert_eq!(signature_name.0.as_slice(), b"signature");
+    assert_eq!(public_key.0.as_slice(), key.public().0);
+    let signature = Signature::from_slice(signature.0.as_slice()).unwrap();
+    verify(&key.public(), &hash, &signature).unwrap();
+
+    // source account credentials need no signature
+    let mut source = entry(key.public().0);
+    source.credentials = SorobanCredentials::SourceAccount;
+    let before = source.clone();
+    key.sign_authorization_entry(&mut source, TEST_NETWORK, 1000)
+        .unwrap();
+    assert_eq!(source, before);
+
+    let mut other = entry([9; 32]);
+    assert!(matches!(
+        key.sign_authorization_entry(&mut other, TEST_NETWORK, 1000),
+        Err(Error::WrongAuthorizationAddress)
+    ));
+}
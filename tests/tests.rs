@@ -1,4 +1,8 @@
-use sep5::SeedPhrase;
+mod common;
+
+use common::TWELVE;
+use sep5::{key_pair::parse_muxed, Error, KeyPair, SeedPhrase, Signature};
+use stellar_strkey::ed25519::PublicKey;
 
 trait ToLowerHex {
     fn to_lower_hex(&self) -> String;
@@ -14,7 +18,6 @@ impl ToLowerHex for &[u8; 32] {
     }
 }
 
-const TWELVE: &str = "illness spike retreat truth genius clock brain pass fit cave bargain toe";
 const TWELVE_WITH_SPACES: &str =
     " illness spike retreat truth genius clock  brain pass fit cave   bargain toe ";
 
@@ -141,6 +144,110 @@ fn test_5_12_world_entry_bytes_00000000000000000000000000000000() {
 ]);
 }
 
+#[test]
+fn sign_and_verify() {
+    let phrase: SeedPhrase = TWELVE.parse().unwrap();
+    let key_pair = phrase.from_path_index(0, None).unwrap();
+    let signature = key_pair.sign(b"hello world");
+    assert_eq!(
+        signature.to_base64(),
+        "LIl9QyAQ7VX7M2fSiCKB0FOMDZ6gQC4nye8R0DfZisXMFAVu+X5Lz6iIHyI81X0LXji6Dmj5TMo+SGER0cr4BA=="
+    );
+    key_pair.verify(b"hello world", &signature).unwrap();
+    assert!(key_pair.verify(b"hello world!", &signature).is_err());
+
+    let decoded: Signature = signature.to_string().parse().unwrap();
+    assert_eq!(decoded, signature);
+    assert_eq!(
+        Signature::from_slice(&signature.to_bytes()).unwrap(),
+        signature
+    );
+    assert!(Signature::from_slice(&[0; 63]).is_err());
+
+    let other = phrase.from_path_index(1, None).unwrap();
+    assert!(other.verify(b"hello world", &signature).is_err());
+}
+
+#[test]
+fn muxed_account() {
+    let key_pair = common::account(0);
+    for (id, address) in [
+        (
+            0,
+            "MDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUIAAAAAAAAAAAAAC6G",
+        ),
+        (
+            1234,
+            "MDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUIAAAAAAAAAAE2L7MI",
+        ),
+        (
+            u64::MAX,
+            "MDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUJ7777777777777SEK",
+        ),
+    ] {
+        let muxed = key_pair.muxed(id);
+        assert_eq!(muxed.to_string().as_str(), address);
+        assert_eq!(parse_muxed(address).unwrap(), (key_pair.public(), id));
+    }
+    assert!(matches!(
+        parse_muxed("GDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUJUJ6"),
+        Err(Error::InvalidMuxedAccount(_))
+    ));
+}
+
+#[test]
+fn debug_redacts_secrets() {
+    let phrase: SeedPhrase = TWELVE.parse().unwrap();
+    let debug = format!("{phrase:?}");
+    assert!(!debug.contains("illness"));
+    assert!(debug.contains("[REDACTED]"));
+
+    let key_pair = phrase.from_path_index(0, None).unwrap();
+    let debug = format!("{key_pair:?}");
+    assert!(debug.contains("GDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUJUJ6"));
+    assert!(!debug.contains("SBGWSG6BTNCKCOB3DIFBGCVMUPQFYPA2G4O34RMTB343OYPXU5DJDVMN"));
+}
+
+#[test]
+fn secret_round_trip() {
+    let phrase: SeedPhrase = TWELVE.parse().unwrap();
+    let derived = phrase.from_path_index(0, None).unwrap();
+    let secret = "SBGWSG6BTNCKCOB3DIFBGCVMUPQFYPA2G4O34RMTB343OYPXU5DJDVMN";
+
+    let key_pair = KeyPair::from_secret(secret).unwrap();
+    assert_eq!(key_pair.public(), derived.public());
+    assert_eq!(key_pair.to_string(), secret);
+    assert_eq!(
+        secret.parse::<KeyPair>().unwrap().public(),
+        derived.public()
+    );
+    assert_eq!(
+        KeyPair::from_bytes(derived.private().0).public(),
+        derived.public()
+    );
+    assert_eq!(KeyPair::from(derived.private()).to_string(), secret);
+
+    for invalid in [
+        "",
+        "GDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUJUJ6",
+        "SBGWSG6BTNCKCOB3DIFBGCVMUPQFYPA2G4O34RMTB343OYPXU5DJDVMA",
+    ] {
+        assert!(matches!(
+            KeyPair::from_secret(invalid),
+            Err(Error::InvalidSecretKey)
+        ));
+    }
+}
+
+#[test]
+fn zeroize_key_pair() {
+    use zeroize::Zeroize;
+    let phrase: SeedPhrase = TWELVE.parse().unwrap();
+    let mut key_pair = phrase.from_path_index(0, None).unwrap();
+    key_pair.zeroize();
+    assert_eq!(key_pair.private().0, [0; 32]);
+}
+
 fn full_test(
     phrase: SeedPhrase,
     passphrase: Option<&str>,
@@ -158,3 +265,56 @@ fn full_test(
         assert_eq!(&key_pair.private().to_string(), *private_key);
     }
 }
+
+#[test]
+fn derive_range() {
+    let phrase: SeedPhrase = TWELVE.parse().unwrap();
+    let keys = phrase.derive_range(3..6, None).unwrap();
+    assert_eq!(keys.len(), 3);
+    for (key_pair, i) in keys.iter().zip(3..) {
+        assert_eq!(
+            key_pair.public(),
+            phrase.from_path_index(i, None).unwrap().public()
+        );
+    }
+    assert!(phrase.derive_range(5..5, None).unwrap().is_empty());
+
+    let root = phrase.derived_root(None).unwrap();
+    let lazy = root
+        .accounts(0..usize::MAX)
+        .take(2)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        lazy[1].public().to_string(),
+        "GBAW5XGWORWVFE2XTJYDTLDHXTY2Q2MO73HYCGB3XMFMQ562Q2W2GJQX"
+    );
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_derive_range() {
+    let phrase: SeedPhrase = TWELVE.parse().unwrap();
+    let sequential = phrase.derive_range(0..20, None).unwrap();
+    let parallel = phrase.par_derive_range(0..20, None).unwrap();
+    assert_eq!(
+        sequential.iter().map(|k| k.public()).collect::<Vec<_>>(),
+        parallel.iter().map(|k| k.public()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn find_account() {
+    let phrase: SeedPhrase = TWELVE.parse().unwrap();
+    let public_key =
+        PublicKey::from_string("GBCUXLFLSL2JE3NWLHAWXQZN6SQC6577YMAU3M3BEMWKYPFWXBSRCWV4").unwrap();
+    let (index, path) = phrase.find_account(&public_key, None, 10).unwrap().unwrap();
+    assert_eq!(index, 4);
+    assert_eq!(path.to_string(), "m/44'/148'/4'");
+
+    assert!(phrase.find_account(&public_key, None, 3).unwrap().is_none());
+    assert!(phrase
+        .find_account(&public_key, Some("passphrase"), 10)
+        .unwrap()
+        .is_none());
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "stellar-xdr")]
+
+mod common;
+
+use common::TWELVE;
+use sep5::{
+    key_pair::verify,
+    signer,
+    transaction::{self, TEST_NETWORK},
+    SeedPhrase, Signature,
+};
+use stellar_xdr::curr::{
+    Asset, FeeBumpTransaction, FeeBumpTransactionEnvelope, FeeBumpTransactionExt,
+    FeeBumpTransactionInnerTx, Limits, Memo, MuxedAccount, Operation, OperationBody, PaymentOp,
+    Preconditions, ReadXdr, SequenceNumber, Transaction, TransactionEnvelope, TransactionExt,
+    TransactionV0, TransactionV0Envelope, TransactionV0Ext, TransactionV1Envelope, Uint256,
+    WriteXdr,
+};
+
+fn transaction(source: [u8; 32], destination: [u8; 32]) -> Transaction {
+    Transaction {
+        source_account: MuxedAccount::Ed25519(Uint256(source)),
+        fee: 100,
+        seq_num: SequenceNumber(1234567890),
+        cond: Preconditions::None,
+        memo: Memo::None,
+        operations: vec![Operation {
+            source_account: None,
+            body: OperationBody::Payment(PaymentOp {
+                destination: MuxedAccount::Ed25519(Uint256(destination)),
+                asset: Asset::Native,
+                amount: 10_000_000,
+            }),
+        }]
+        .try_into()
+        .unwrap(),
+        ext: TransactionExt::V0,
+    }
+}
+
+#[test]
+fn sign_envelopes() {
+    let keys = SeedPhrase::from_seed_phrase(TWELVE)
+        .unwrap()
+        .derive_range(0..2, None)
+        .unwrap();
+    let (source, destination) = (&keys[0], &keys[1]);
+    let tx = transaction(source.public().0, destination.public().0);
+
+    let mut envelope = TransactionEnvelope::Tx(TransactionV1Envelope {
+        tx: tx.clone(),
+        signatures: Default::default(),
+    });
+    let hash = transaction::hash(&envelope, TEST_NETWORK).unwrap();
+    assert_eq!(
+        hex::encode(hash),
+        "d7ec508d3803d2fd13aa075bc07bc4bc53efd55ef144454d160bc47af7fab8c8"
+    );
+    assert_eq!(
+        signer::pre_auth_tx_envelope(&envelope, TEST_NETWORK).unwrap(),
+        signer::pre_auth_tx(hash)
+    );
+
+    // V0 envelopes sign the same hash as the equivalent V1 transaction
+    let v0 = TransactionEnvelope::TxV0(TransactionV0Envelope {
+        tx: TransactionV0 {
+            source_account_ed25519: Uint256(source.public().0),
+            fee: tx.fee,
+            seq_num: tx.seq_num.clone(),
+            time_bounds: None,
+            memo: tx.memo.clone(),
+            operations: tx.operations.clone(),
+            ext: TransactionV0Ext::V0,
+        },
+        signatures: Default::default(),
+    });
+    assert_eq!(transaction::hash(&v0, TEST_NETWORK).unwrap(), hash);
+
+    let fee_bump = TransactionEnvelope::TxFeeBump(FeeBumpTransactionEnvelope {
+        tx: FeeBumpTransaction {
+            fee_source: MuxedAccount::Ed25519(Uint256(destination.public().0)),
+            fee: 400,
+            inner_tx: FeeBumpTransactionInnerTx::Tx(TransactionV1Envelope {
+                tx,
+                signatures: Default::default(),
+            }),
+            ext: FeeBumpTransactionExt::V0,
+        },
+        signatures: Default::default(),
+    });
+    let fee_bump_hash = transaction::hash(&fee_bump, TEST_NETWORK).unwrap();
+    assert_eq!(
+        hex::encode(fee_bump_hash),
+        "7093abd97ac009a7106657686d6a4394acd1e5cc4c11e91cde8798126fbaf2a7"
+    );
+
+    source
+        .sign_transaction(&mut envelope, TEST_NETWORK)
+        .unwrap();
+    let TransactionEnvelope::Tx(signed) = &envelope else {
+        unreachable!()
+    };
+    let [signature] = signed.signatures.as_slice() else {
+        panic!("expected one signature");
+    };
+    assert_eq!(signature.hint.0, source.public().0[28..]);
+    let signature = Signature::from_slice(&signature.signature.0).unwrap();
+    verify(&source.public(), &hash, &signature).unwrap();
+
+    let xdr = fee_bump.to_xdr_base64(Limits::none()).unwrap();
+    let signed = destination
+        .sign_transaction_xdr(&xdr, TEST_NETWORK)
+        .unwrap();
+    let signed = TransactionEnvelope::from_xdr_base64(signed, Limits::none()).unwrap();
+    let TransactionEnvelope::TxFeeBump(signed) = signed else {
+        unreachable!()
+    };
+    assert_eq!(signed.signatures.len(), 1);
+    assert_eq!(signed.signatures[0].hint, destination.signature_hint());
+    let signature = Signature::from_slice(&signed.signatures[0].signature.0).unwrap();
+    verify(&destination.public(), &fee_bump_hash, &signature).unwrap();
+}
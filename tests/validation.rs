@@ -0,0 +1,77 @@
+mod common;
+
+use common::TWELVE;
+use sep5::{validation, Error, Language, SeedPhrase};
+
+#[test]
+fn valid_phrase() {
+    let report = validation::validate(TWELVE);
+    assert!(report.is_valid());
+    assert_eq!(report.language, Language::English);
+    assert_eq!(report.word_count, 12);
+    assert_eq!(report.checksum_valid, Some(true));
+    assert_eq!(report.to_string(), "valid");
+}
+
+#[test]
+fn unknown_words_with_suggestions() {
+    let report = validation::validate(
+        "ilness spike retreat truth genius clock brain pass fit cave bargainn toe",
+    );
+    assert!(!report.is_valid());
+    assert!(report.word_count_valid);
+    assert_eq!(report.checksum_valid, None);
+    let unknown = report
+        .unknown_words
+        .iter()
+        .map(|w| (w.position, w.word.as_str(), w.suggestions[0].as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        unknown,
+        [(0, "ilness", "illness"), (10, "bargainn", "bargain")]
+    );
+
+    // the first four letters identify a word
+    let report = validation::validate_in("abou", Language::English);
+    assert_eq!(
+        report.unknown_words[0].suggestions,
+        ["about", "able", "above", "atom", "box"]
+    );
+    let report = validation::validate_in("zoooo", Language::English);
+    assert_eq!(report.unknown_words[0].suggestions, ["zoo"]);
+}
+
+#[test]
+fn word_count_and_checksum() {
+    let report = validation::validate("illness spike retreat");
+    assert!(!report.word_count_valid);
+    assert_eq!(report.word_count, 3);
+    assert_eq!(report.checksum_valid, None);
+
+    let swapped = "spike illness retreat truth genius clock brain pass fit cave bargain toe";
+    let report = validation::validate(swapped);
+    assert!(report.unknown_words.is_empty());
+    assert_eq!(report.checksum_valid, Some(false));
+    assert_eq!(report.to_string(), "invalid checksum");
+}
+
+#[test]
+fn from_seed_phrase_reports_every_problem() {
+    let Err(Error::InvalidSeedPhrase(report)) =
+        SeedPhrase::from_seed_phrase("illness spike retreat truth genius clockk brain")
+    else {
+        panic!("expected an invalid seed phrase");
+    };
+    assert!(!report.word_count_valid);
+    assert_eq!(report.unknown_words[0].position, 5);
+    assert_eq!(
+        report.to_string(),
+        "expected 12, 15, 18, 21 or 24 words, found 7; unknown word at position 6 (4 suggestions)"
+    );
+    assert_eq!(report.unknown_words[0].word, "clockk");
+    assert_eq!(
+        report.unknown_words[0].suggestions,
+        ["clock", "click", "flock", "lock"]
+    );
+    assert!(!format!("{report:?}").contains("clock"));
+}
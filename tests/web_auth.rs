@@ -0,0 +1,260 @@
+#![cfg(feature = "stellar-xdr")]
+
+mod common;
+
+use sep5::{
+    error::ChallengeError,
+    key_pair::verify,
+    transaction::{self, TEST_NETWORK},
+    web_auth::{ClientDomain, WebAuth},
+    Error, KeyPair, SeedPhrase, Signature,
+};
+use stellar_xdr::curr::{
+    DataValue, Limits, ManageDataOp, Memo, MuxedAccount, MuxedAccountMed25519, Operation,
+    OperationBody, Preconditions, ReadXdr, SequenceNumber, String64, TimeBounds, TimePoint,
+    Transaction, TransactionEnvelope, TransactionExt, TransactionV1Envelope, Uint256, WriteXdr,
+};
+
+const NOW: u64 = 1_700_000_000;
+
+fn keys() -> (KeyPair, KeyPair) {
+    let client = common::account(3);
+    let server = SeedPhrase::from_entropy(&[42; 16])
+        .unwrap()
+        .from_path_index(0, None)
+        .unwrap();
+    (client, server)
+}
+
+fn manage_data(source: &KeyPair, name: &str, value: &[u8]) -> Operation {
+    Operation {
+        source_account: Some(MuxedAccount::Ed25519(Uint256(source.public().0))),
+        body: OperationBody::ManageData(ManageDataOp {
+            data_name: String64(name.try_into().unwrap()),
+            data_value: Some(DataValue(value.try_into().unwrap())),
+        }),
+    }
+}
+
+/// A challenge as a SEP-10 server would build it, passed through `modify`
+/// before being signed by the server
+fn challenge(modify: impl FnOnce(&mut Transaction)) -> String {
+    let (client, server) = keys();
+    let mut tx = Transaction {
+        source_account: MuxedAccount::Ed25519(Uint256(server.public().0)),
+        fee: 200,
+        seq_num: SequenceNumber(0),
+        cond: Preconditions::Time(TimeBounds {
+            min_time: TimePoint(NOW),
+            max_time: TimePoint(NOW + 900),
+        }),
+        memo: Memo::None,
+        operations: vec![
+            manage_data(&client, "example.com auth", &[b'n'; 64]),
+            manage_data(&server, "web_auth_domain", b"auth.example.com"),
+        ]
+        .try_into()
+        .unwrap(),
+        ext: TransactionExt::V0,
+    };
+    modify(&mut tx);
+    let mut envelope = TransactionEnvelope::Tx(TransactionV1Envelope {
+        tx,
+        signatures: Default::default(),
+    });
+    server
+        .sign_transaction(&mut envelope, TEST_NETWORK)
+        .unwrap();
+    envelope.to_xdr_base64(Limits::none()).unwrap()
+}
+
+fn web_auth(server: &KeyPair) -> WebAuth<'static> {
+    WebAuth {
+        server_account: server.public(),
+        home_domain: "example.com",
+        web_auth_domain: "auth.example.com",
+        network_passphrase: TEST_NETWORK,
+        client_domain: None,
+    }
+}
+
+fn client_domain_key() -> KeyPair {
+    SeedPhrase::from_entropy(&[11; 16])
+        .unwrap()
+        .from_path_index(0, None)
+        .unwrap()
+}
+
+/// A challenge with a client domain operation from `source`
+fn client_domain_challenge(source: &KeyPair, domain: &[u8]) -> String {
+    let (client, server) = keys();
+    challenge(|tx| {
+        tx.operations = vec![
+            manage_data(&client, "example.com auth", &[b'n'; 64]),
+            manage_data(&server, "web_auth_domain", b"auth.example.com"),
+            manage_data(source, "client_domain", domain),
+        ]
+        .try_into()
+        .unwrap()
+    })
+}
+
+#[test]
+fn sign_valid_challenge() {
+    let (client, server) = keys();
+    let signed = web_auth(&server)
+        .sign_challenge_at(&challenge(|_| {}), &client, NOW + 10)
+        .unwrap();
+    let envelope = TransactionEnvelope::from_xdr_base64(signed, Limits::none()).unwrap();
+    let hash = transaction::hash(&envelope, TEST_NETWORK).unwrap();
+    let TransactionEnvelope::Tx(envelope) = envelope else {
+        unreachable!()
+    };
+    assert_eq!(envelope.signatures.len(), 2);
+    assert_eq!(envelope.signatures[1].hint, client.signature_hint());
+    let signature = Signature::from_slice(&envelope.signatures[1].signature.0).unwrap();
+    verify(&client.public(), &hash, &signature).unwrap();
+}
+
+#[test]
+fn reject_invalid_challenges() {
+    let (client, server) = keys();
+    let other = SeedPhrase::from_entropy(&[7; 16])
+        .unwrap()
+        .from_path_index(0, None)
+        .unwrap();
+    let cases: Vec<(String, u64, ChallengeError)> = vec![
+        (challenge(|_| {}), NOW + 901, ChallengeError::Expired),
+        (
+            challenge(|tx| tx.seq_num = SequenceNumber(1)),
+            NOW,
+            ChallengeError::NonZeroSequence,
+        ),
+        (
+            challenge(|tx| tx.cond = Preconditions::None),
+            NOW,
+            ChallengeError::MissingTimeBounds,
+        ),
+        (
+            challenge(|tx| {
+                tx.operations = vec![manage_data(&client, "evil.com auth", &[b'n'; 64])]
+                    .try_into()
+                    .unwrap()
+            }),
+            NOW,
+            ChallengeError::WrongHomeDomain,
+        ),
+        (
+            challenge(|tx| {
+                tx.operations = vec![manage_data(&other, "example.com auth", &[b'n'; 64])]
+                    .try_into()
+                    .unwrap()
+            }),
+            NOW,
+            ChallengeError::WrongClientAccount,
+        ),
+        (
+            challenge(|tx| {
+                tx.operations = vec![
+                    manage_data(&client, "example.com auth", &[b'n'; 64]),
+                    manage_data(&client, "transfer", b"everything"),
+                ]
+                .try_into()
+                .unwrap()
+            }),
+            NOW,
+            ChallengeError::UnexpectedOperationSource,
+        ),
+        (
+            challenge(|tx| {
+                tx.operations = vec![
+                    manage_data(&client, "example.com auth", &[b'n'; 64]),
+                    manage_data(&server, "web_auth_domain", b"evil.com"),
+                ]
+                .try_into()
+                .unwrap()
+            }),
+            NOW,
+            ChallengeError::WrongWebAuthDomain,
+        ),
+    ];
+    for (challenge, now, expected) in cases {
+        match web_auth(&server).sign_challenge_at(&challenge, &client, now) {
+            Err(Error::InvalidChallenge(err)) => assert_eq!(err, expected),
+            other => panic!("expected {expected:?}, got {other:?}"),
+        }
+    }
+
+    // muxed server account
+    let muxed = challenge(|tx| {
+        tx.source_account = MuxedAccount::MuxedEd25519(MuxedAccountMed25519 {
+            id: 1,
+            ed25519: Uint256(server.public().0),
+        })
+    });
+    match web_auth(&server).sign_challenge_at(&muxed, &client, NOW) {
+        Err(Error::InvalidChallenge(err)) => assert_eq!(err, ChallengeError::WrongSourceAccount),
+        other => panic!("expected a wrong source account, got {other:?}"),
+    }
+
+    // the end of time
+    match web_auth(&server).sign_challenge_at(&challenge(|_| {}), &client, u64::MAX) {
+        Err(Error::InvalidChallenge(err)) => assert_eq!(err, ChallengeError::Expired),
+        other => panic!("expected an expired challenge, got {other:?}"),
+    }
+
+    // signed by another server
+    match web_auth(&other).sign_challenge_at(&challenge(|_| {}), &client, NOW) {
+        Err(Error::InvalidChallenge(err)) => assert_eq!(err, ChallengeError::WrongSourceAccount),
+        other => panic!("expected a wrong source account, got {other:?}"),
+    }
+}
+
+#[test]
+fn client_domain() {
+    let (client, server) = keys();
+    let client_domain_key = client_domain_key();
+    let web_auth = WebAuth {
+        client_domain: Some(ClientDomain {
+            domain: "wallet.example.com",
+            signing_key: client_domain_key.public(),
+        }),
+        ..web_auth(&server)
+    };
+    web_auth
+        .sign_challenge_at(
+            &client_domain_challenge(&client_domain_key, b"wallet.example.com"),
+            &client,
+            NOW,
+        )
+        .unwrap();
+
+    let cases = [
+        (
+            web_auth.clone(),
+            client_domain_challenge(&client, b"wallet.example.com"),
+            ChallengeError::WrongClientDomain,
+        ),
+        (
+            web_auth.clone(),
+            client_domain_challenge(&client_domain_key, b"evil.com"),
+            ChallengeError::WrongClientDomain,
+        ),
+        (
+            web_auth.clone(),
+            challenge(|_| {}),
+            ChallengeError::MissingClientDomain,
+        ),
+        (
+            self::web_auth(&server),
+            client_domain_challenge(&client_domain_key, b"wallet.example.com"),
+            ChallengeError::UnexpectedClientDomain,
+        ),
+    ];
+    for (web_auth, challenge, expected) in cases {
+        match web_auth.sign_challenge_at(&challenge, &client, NOW) {
+            Err(Error::InvalidChallenge(err)) => assert_eq!(err, expected),
+            other => panic!("expected {expected:?}, got {other:?}"),
+        }
+    }
+}